@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::char;
+#[cfg(feature = "heteronym")]
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::convert::TryFrom;
@@ -8,16 +9,28 @@ use std::fs::File;
 use std::io::{self, BufWriter, Write};
 use std::path::Path;
 
+#[cfg(any(
+    feature = "yale",
+    feature = "gwoyeu_romatzyh",
+    feature = "mps2",
+    feature = "tongyong",
+    feature = "cyrillic",
+    feature = "ipa",
+    feature = "hangul",
+    feature = "katakana"
+))]
+#[path = "build/romanization.rs"]
+mod romanization;
+
 const RAW_DATA: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/pinyin-data/pinyin.txt"
 ));
+const SURNAME_DATA: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/surnames.txt"));
+#[cfg(feature = "place_name")]
+const PLACE_NAME_DATA: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/place_names.txt"));
 
-#[cfg(any(
-    feature = "plain",
-    feature = "with_tone_num",
-    feature = "with_tone_num_end"
-))]
 const PHONETIC_SYMBOL_MAP: &[(char, char, u8)] = &[
     ('ā', 'a', 1),
     ('á', 'a', 2),
@@ -79,8 +92,21 @@ const LETTER_TABLE: &[char] = &[
 #[cfg(any(feature = "with_tone_num", feature = "with_tone_num_end"))]
 const TONE_NUMS: &[char] = &['0', '1', '2', '3', '4'];
 
+/// 语气词 啊 受前一音节影响产生的各种音变
+#[cfg(feature = "particle_a")]
+const PARTICLE_A_PINYIN: [&str; 5] = ["ya", "wa", "na", "nga", "ra"];
+
+/// 了、着 不作助词时的读音
+#[cfg(feature = "neutral_tone")]
+const FULL_TONE_PINYIN: [&str; 3] = ["liǎo", "zháo", "zhuó"];
+
+/// 一、不 变调后的读音
+#[cfg(feature = "tone_sandhi")]
+const TONE_SANDHI_PINYIN: [&str; 3] = ["yí", "yì", "bú"];
+
 type Style = (&'static str, fn(&str) -> Cow<'_, str>);
 type InputData = Vec<(u32, Vec<&'static str>)>;
+type PhraseData = Vec<(&'static str, Vec<&'static str>)>;
 type PinyinDataIndex = HashMap<&'static str, usize>;
 type HeteronymDataIndex = HashMap<u32, usize>;
 
@@ -89,8 +115,24 @@ fn main() -> io::Result<()> {
     let pinyin_index = generate_pinyin_data(&data)?;
     let heteronym_index = generate_heteronym_table(&data, &pinyin_index)?;
     generate_char_table(&data, &pinyin_index, &heteronym_index)?;
+    let surnames = build_phrase_data(&data, SURNAME_DATA);
+    generate_phrase_table("surname_table.rs", &surnames, &pinyin_index)?;
+    #[cfg(feature = "place_name")]
+    {
+        let place_names = build_phrase_data(&data, PLACE_NAME_DATA);
+        generate_phrase_table("place_name_table.rs", &place_names, &pinyin_index)?;
+    }
+    #[cfg(feature = "particle_a")]
+    generate_particle_a_table(&pinyin_index)?;
+    #[cfg(feature = "neutral_tone")]
+    generate_full_tone_table(&pinyin_index)?;
+    #[cfg(feature = "tone_sandhi")]
+    generate_tone_sandhi_table(&pinyin_index)?;
+    #[cfg(feature = "plain")]
+    generate_syllable_table(&data)?;
     // 输出这行以保证改动项目的其他文件不会触发编译脚本重新执行
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build");
     Ok(())
 }
 
@@ -144,6 +186,55 @@ fn build_data() -> InputData {
     input_data
 }
 
+fn build_phrase_data(data: &InputData, raw: &'static str) -> PhraseData {
+    let mut phrase_data = raw
+        .lines()
+        .enumerate()
+        // 移除注释和空格
+        .map(|(i, mut line)| {
+            if let Some(hash_pos) = line.find('#') {
+                line = &line[..hash_pos];
+            }
+            (i, line.trim())
+        })
+        // 移除空行
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            let colon_pos = match line.find(':') {
+                Some(pos) => pos,
+                None => unreachable!("no colon found in line {}", i),
+            };
+            let phrase = line[..colon_pos].trim();
+            let pinyin_list: Vec<_> = line[colon_pos + 1..].split_whitespace().collect();
+            assert_eq!(
+                phrase.chars().count(),
+                pinyin_list.len(),
+                "mismatched pinyin count at line {}: {}",
+                i,
+                line,
+            );
+            // 确保词语中每个字的拼音都是拼音数据中该字已有的读音
+            for (ch, pinyin) in phrase.chars().zip(pinyin_list.iter()) {
+                let code = u32::from(ch);
+                let is_known = data
+                    .binary_search_by_key(&code, |(code, _)| *code)
+                    .is_ok_and(|idx| data[idx].1.contains(pinyin));
+                assert!(
+                    is_known,
+                    "unknown pinyin {:?} for {:?} at line {}: {}",
+                    pinyin, ch, i, line,
+                );
+            }
+            (phrase, pinyin_list)
+        })
+        .collect::<Vec<_>>();
+    phrase_data.sort_by_key(|(phrase, _)| *phrase);
+    for pair in phrase_data.windows(2) {
+        assert_ne!(pair[0].0, pair[1].0, "duplicate phrase {:?}", pair[0].0);
+    }
+    phrase_data
+}
+
 const STYLES: &[Style] = &[
     #[cfg(feature = "plain")]
     ("plain", |input| {
@@ -184,25 +275,84 @@ const STYLES: &[Style] = &[
         }
         result.into()
     }),
+    #[cfg(feature = "yale")]
+    ("yale", |input| romanization::yale(input, false).into()),
+    #[cfg(feature = "yale")]
+    ("yale_num", |input| romanization::yale(input, true).into()),
+    #[cfg(feature = "mps2")]
+    ("mps2", |input| romanization::mps2(input, true).into()),
+    #[cfg(feature = "mps2")]
+    ("mps2_plain", |input| {
+        romanization::mps2(input, false).into()
+    }),
+    #[cfg(feature = "tongyong")]
+    ("tongyong", |input| {
+        romanization::tongyong(input, true).into()
+    }),
+    #[cfg(feature = "tongyong")]
+    ("tongyong_plain", |input| {
+        romanization::tongyong(input, false).into()
+    }),
+    #[cfg(feature = "cyrillic")]
+    ("cyrillic", |input| romanization::cyrillic(input).into()),
+    #[cfg(feature = "ipa")]
+    ("ipa", |input| romanization::ipa(input, false).into()),
+    #[cfg(feature = "ipa")]
+    ("ipa_num", |input| romanization::ipa(input, true).into()),
+    #[cfg(feature = "gwoyeu_romatzyh")]
+    ("gwoyeu_romatzyh", |input| {
+        romanization::gwoyeu_romatzyh(input).into()
+    }),
+    #[cfg(feature = "hangul")]
+    ("hangul", |input| romanization::hangul(input).into()),
+    #[cfg(feature = "katakana")]
+    ("katakana", |input| romanization::katakana(input).into()),
 ];
 
 fn generate_pinyin_data(data: &InputData) -> io::Result<PinyinDataIndex> {
-    let mut output = create_out_file("pinyin_data.rs")?;
     let mut pinyin_data = HashMap::new();
-    writeln!(output, "&[")?;
-    let mut process_pinyin = |pinyin| -> io::Result<()> {
-        let index = pinyin_data.len();
-        match pinyin_data.entry(pinyin) {
-            Entry::Occupied(_) => return Ok(()),
-            Entry::Vacant(entry) => {
-                entry.insert(index);
-            }
+    // 插入一个空的拼音数据作为零位
+    let mut pinyin_list = vec![];
+    insert_pinyin(&mut pinyin_list, &mut pinyin_data, "");
+    data.iter()
+        .flat_map(|(_, list)| list.iter().copied())
+        .for_each(|pinyin| {
+            insert_pinyin(&mut pinyin_list, &mut pinyin_data, pinyin);
+        });
+    // 轻声音节和儿化音节不一定在原始数据之中，需要额外生成
+    #[cfg(feature = "tone_sandhi")]
+    for pinyin in TONE_SANDHI_PINYIN {
+        insert_pinyin(&mut pinyin_list, &mut pinyin_data, pinyin);
+    }
+    #[cfg(feature = "particle_a")]
+    for pinyin in PARTICLE_A_PINYIN {
+        insert_pinyin(&mut pinyin_list, &mut pinyin_data, pinyin);
+    }
+    #[cfg(feature = "neutral_tone")]
+    {
+        for pinyin in FULL_TONE_PINYIN {
+            insert_pinyin(&mut pinyin_list, &mut pinyin_data, pinyin);
         }
+        insert_derived_pinyin(&mut pinyin_list, &mut pinyin_data, neutral_form);
+    }
+    #[cfg(feature = "erhua")]
+    insert_derived_pinyin(&mut pinyin_list, &mut pinyin_data, erhua_form);
+    #[cfg(all(feature = "neutral_tone", feature = "erhua"))]
+    insert_derived_pinyin(&mut pinyin_list, &mut pinyin_data, neutral_form);
+
+    let mut output = create_out_file("pinyin_data.rs")?;
+    writeln!(output, "&[")?;
+    for pinyin in pinyin_list.iter() {
         write!(output, "    PinyinData {{ ")?;
         for (field, converter) in STYLES.iter() {
             write!(output, r#"{}: "{}", "#, field, converter(pinyin))?;
         }
-        #[cfg(feature = "compat")]
+        #[cfg(any(
+            feature = "compat",
+            feature = "particle_a",
+            feature = "braille",
+            feature = "phoneme"
+        ))]
         {
             // 计算切分声母和韵母的位置
             const INITIALS: &[&str] = &[
@@ -215,18 +365,76 @@ fn generate_pinyin_data(data: &InputData) -> io::Result<PinyinDataIndex> {
                 .map_or(0, |initial| initial.len());
             write!(output, "split: {split}, ")?;
         }
+        let tone = pinyin.chars().map(|c| get_char_info(c).1).max();
+        write!(output, "tone: {}, ", tone.unwrap_or(0))?;
+        #[cfg(feature = "erhua")]
+        {
+            let erhua = erhua_form(pinyin).map_or(0, |erhua| pinyin_data[erhua.as_str()]);
+            write!(output, "erhua: {erhua}, ")?;
+        }
+        #[cfg(feature = "neutral_tone")]
+        {
+            let neutral = pinyin_data[neutral_form(pinyin).unwrap().as_str()];
+            write!(output, "neutral: {neutral}, ")?;
+        }
         writeln!(output, "}},")?;
-        Ok(())
-    };
-    // 插入一个空的拼音数据作为零位
-    process_pinyin("")?;
-    data.iter()
-        .flat_map(|(_, list)| list.iter().copied())
-        .try_for_each(process_pinyin)?;
+    }
     writeln!(output, "]")?;
     Ok(pinyin_data)
 }
 
+fn insert_pinyin(
+    list: &mut Vec<&'static str>,
+    index: &mut PinyinDataIndex,
+    pinyin: &'static str,
+) -> usize {
+    let new_idx = list.len();
+    *index.entry(pinyin).or_insert_with(|| {
+        list.push(pinyin);
+        new_idx
+    })
+}
+
+/// 为列表中的每个拼音生成派生的拼音，并将其中新的拼音加入列表
+#[cfg(any(feature = "erhua", feature = "neutral_tone"))]
+fn insert_derived_pinyin(
+    list: &mut Vec<&'static str>,
+    index: &mut PinyinDataIndex,
+    derive: fn(&str) -> Option<String>,
+) {
+    for i in 0..list.len() {
+        if let Some(derived) = derive(list[i]) {
+            if !index.contains_key(derived.as_str()) {
+                // 编译脚本只运行一次，这里直接泄漏内存以获得 `'static` 生命周期
+                insert_pinyin(list, index, Box::leak(derived.into_boxed_str()));
+            }
+        }
+    }
+}
+
+/// 生成音节的儿化形式，如 `huā` 的儿化形式为 `huār`
+///
+/// 音节 `er` 本身以及不含元音的音节（如 `m̄`、`hng`）没有儿化形式。
+#[cfg(feature = "erhua")]
+fn erhua_form(pinyin: &str) -> Option<String> {
+    const VOWELS: &str = "aāáǎàeēéěèiīíǐìoōóǒòuūúǔùüǘǚǜ";
+    if pinyin.ends_with('r') || !pinyin.chars().any(|ch| VOWELS.contains(ch)) {
+        return None;
+    }
+    Some(format!("{pinyin}r"))
+}
+
+/// 生成音节的轻声形式，即去掉声调后的形式，如 `mā` 的轻声形式为 `ma`
+#[cfg(feature = "neutral_tone")]
+fn neutral_form(pinyin: &str) -> Option<String> {
+    Some(
+        pinyin
+            .chars()
+            .filter_map(|ch| get_char_info(ch).0)
+            .collect(),
+    )
+}
+
 #[cfg(feature = "heteronym")]
 fn generate_heteronym_table(
     data: &InputData,
@@ -324,16 +532,86 @@ fn generate_char_table(
     Ok(())
 }
 
+fn generate_phrase_table(
+    name: &str,
+    data: &PhraseData,
+    pinyin_index: &PinyinDataIndex,
+) -> io::Result<()> {
+    let mut output = create_out_file(name)?;
+    writeln!(output, "&[")?;
+    for (phrase, list) in data.iter() {
+        write!(output, "    (\"{phrase}\", &[")?;
+        for pinyin in list.iter() {
+            write!(output, "{}, ", pinyin_index.get(pinyin).unwrap())?;
+        }
+        writeln!(output, "]),")?;
+    }
+    writeln!(output, "]")?;
+    Ok(())
+}
+
+#[cfg(feature = "particle_a")]
+fn generate_particle_a_table(pinyin_index: &PinyinDataIndex) -> io::Result<()> {
+    let mut output = create_out_file("particle_a_table.rs")?;
+    write!(output, "[")?;
+    for pinyin in PARTICLE_A_PINYIN {
+        write!(output, "{}, ", pinyin_index[pinyin])?;
+    }
+    writeln!(output, "]")?;
+    Ok(())
+}
+
+#[cfg(feature = "neutral_tone")]
+fn generate_full_tone_table(pinyin_index: &PinyinDataIndex) -> io::Result<()> {
+    let mut output = create_out_file("full_tone_table.rs")?;
+    write!(output, "[")?;
+    for pinyin in FULL_TONE_PINYIN {
+        write!(output, "{}, ", pinyin_index[pinyin])?;
+    }
+    writeln!(output, "]")?;
+    Ok(())
+}
+
+#[cfg(feature = "tone_sandhi")]
+fn generate_tone_sandhi_table(pinyin_index: &PinyinDataIndex) -> io::Result<()> {
+    let mut output = create_out_file("tone_sandhi_table.rs")?;
+    write!(output, "[")?;
+    for pinyin in TONE_SANDHI_PINYIN {
+        write!(output, "{}, ", pinyin_index[pinyin])?;
+    }
+    writeln!(output, "]")?;
+    Ok(())
+}
+
+/// 生成数据中出现的所有不带声调的音节，按字典序排列
+#[cfg(feature = "plain")]
+fn generate_syllable_table(data: &InputData) -> io::Result<()> {
+    let mut syllables = data
+        .iter()
+        .flat_map(|(_, list)| list.iter())
+        .map(|pinyin| {
+            pinyin
+                .chars()
+                .filter_map(|c| get_char_info(c).0)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    syllables.sort();
+    syllables.dedup();
+    let mut output = create_out_file("syllable_table.rs")?;
+    writeln!(output, "&[")?;
+    for syllable in syllables.iter() {
+        writeln!(output, "    \"{syllable}\",")?;
+    }
+    writeln!(output, "]")?;
+    Ok(())
+}
+
 fn create_out_file(name: &str) -> io::Result<impl Write> {
     let path = Path::new(&env::var("OUT_DIR").unwrap()).join(name);
     Ok(BufWriter::new(File::create(&path)?))
 }
 
-#[cfg(any(
-    feature = "plain",
-    feature = "with_tone_num",
-    feature = "with_tone_num_end"
-))]
 fn get_char_info(ch: char) -> (Option<char>, u8) {
     if let Some((_, base, tone)) = PHONETIC_SYMBOL_MAP.iter().find(|(c, _, _)| *c == ch) {
         return (Some(*base), *tone);
@@ -0,0 +1,836 @@
+//! 由汉语拼音生成其它拼写系统的音节
+//!
+//! 各个拼写系统都从 [`Syllable`] 出发，它把拼音还原为声母、完整的韵母以及声调，
+//! 如 `gui` 还原为 `g` + `uei`，`yu` 还原为零声母 + `ü`。
+
+use super::get_char_info;
+
+/// 拆分后的拼音音节
+pub struct Syllable {
+    /// 声母，零声母为空字符串
+    pub initial: &'static str,
+    /// 完整的韵母，如 `iou`、`uei`、`ü`，舌尖元音也记为 `i`
+    pub final_: String,
+    /// 声调，`1` 到 `4` 表示四声，`0` 表示轻声
+    // 部分拼写系统（如西里尔字母转写）不标注声调
+    #[allow(dead_code)]
+    pub tone: u8,
+    /// 是否为儿化音节
+    pub erhua: bool,
+}
+
+impl Syllable {
+    /// 拆分带声调的拼音
+    pub fn parse(pinyin: &str) -> Syllable {
+        const INITIALS: &[&str] = &[
+            "zh", "ch", "sh", "b", "p", "m", "f", "d", "t", "n", "l", "g", "k", "h", "j", "q", "x",
+            "r", "z", "c", "s",
+        ];
+        let mut plain: String = pinyin.chars().filter_map(|c| get_char_info(c).0).collect();
+        let tone = pinyin
+            .chars()
+            .map(|c| get_char_info(c).1)
+            .max()
+            .unwrap_or(0);
+        // 只有 er 本身不是儿化音节，ar、or 等两个字母的儿化音节也需要识别
+        let erhua = plain != "er" && plain.ends_with('r');
+        if erhua {
+            plain.pop();
+        }
+        let (initial, rest) = match plain.as_str() {
+            // 不含元音的音节以及语气词 啊 的音变 nga
+            "m" | "n" | "ng" | "hm" | "hng" => ("", plain.as_str()),
+            _ if plain.starts_with("ng") => ("ng", &plain[2..]),
+            _ => INITIALS
+                .iter()
+                .find(|initial| plain.starts_with(*initial))
+                .map_or(("", plain.as_str()), |initial| {
+                    (*initial, &plain[initial.len()..])
+                }),
+        };
+        let final_ = match (initial, rest) {
+            ("", "yi" | "yin" | "ying") => rest[1..].to_string(),
+            ("", "yu" | "yue" | "yuan" | "yun") => format!("ü{}", &rest[2..]),
+            ("", "wu") => "u".to_string(),
+            ("", _) if rest.starts_with('y') => format!("i{}", &rest[1..]),
+            ("", _) if rest.starts_with('w') => format!("u{}", &rest[1..]),
+            ("j" | "q" | "x", _) if rest.starts_with('u') => format!("ü{}", &rest[1..]),
+            (_, "iu") => "iou".to_string(),
+            (_, "ui") => "uei".to_string(),
+            (_, "un") => "uen".to_string(),
+            _ => rest.to_string(),
+        };
+        Syllable {
+            initial,
+            final_,
+            tone,
+            erhua,
+        }
+    }
+
+    /// 是否为舌尖元音音节，如 `zhi`、`si`
+    pub fn is_apical(&self) -> bool {
+        self.final_ == "i" && matches!(self.initial, "zh" | "ch" | "sh" | "r" | "z" | "c" | "s")
+    }
+}
+
+/// 四声的声调符号
+#[cfg(any(feature = "yale", feature = "mps2", feature = "tongyong"))]
+const TONE_MARKS: [char; 4] = ['\u{304}', '\u{301}', '\u{30C}', '\u{300}'];
+
+/// 返回声调对应的组合符号，轻声没有声调符号
+#[cfg(any(feature = "yale", feature = "mps2", feature = "tongyong"))]
+fn tone_mark(tone: u8) -> Option<char> {
+    (1..=4)
+        .contains(&tone)
+        .then(|| TONE_MARKS[usize::from(tone - 1)])
+}
+
+/// 在主要元音上标注给定的组合符号
+///
+/// 依次选择 a、e、o，然后是最后一个 i、u，没有元音时选择 `r`、`z` 或者鼻音。
+/// 有对应的预组合字符时使用预组合字符。
+#[cfg(any(feature = "yale", feature = "mps2", feature = "tongyong"))]
+fn put_tone_mark(text: &str, mark: Option<char>) -> String {
+    const PRECOMPOSED: &[(char, [char; 4])] = &[
+        ('a', ['ā', 'á', 'ǎ', 'à']),
+        ('e', ['ē', 'é', 'ě', 'è']),
+        ('i', ['ī', 'í', 'ǐ', 'ì']),
+        ('o', ['ō', 'ó', 'ǒ', 'ò']),
+        ('u', ['ū', 'ú', 'ǔ', 'ù']),
+    ];
+    let mark = match mark {
+        Some(mark) => mark,
+        None => return text.to_string(),
+    };
+    let chars: Vec<_> = text.chars().collect();
+    let index = ['a', 'e', 'ê', 'o']
+        .iter()
+        .find_map(|vowel| chars.iter().position(|ch| ch == vowel))
+        .or_else(|| chars.iter().rposition(|ch| matches!(ch, 'i' | 'u')))
+        .or_else(|| chars.iter().rposition(|ch| matches!(ch, 'r' | 'z')))
+        .or_else(|| chars.iter().position(|ch| matches!(ch, 'm' | 'n')))
+        .unwrap_or(0);
+    let mut result = String::new();
+    for (i, ch) in chars.into_iter().enumerate() {
+        let precomposed = TONE_MARKS
+            .iter()
+            .position(|tone| *tone == mark)
+            .and_then(|tone| {
+                PRECOMPOSED
+                    .iter()
+                    .find(|(base, _)| *base == ch)
+                    .map(|(_, marked)| marked[tone])
+            });
+        match precomposed {
+            _ if i != index => result.push(ch),
+            Some(marked) => result.push(marked),
+            None => {
+                result.push(ch);
+                result.push(mark);
+            }
+        }
+    }
+    result
+}
+
+/// 耶鲁拼音，`numbered` 为真时以数字而不是符号表示声调
+#[cfg(feature = "yale")]
+pub fn yale(pinyin: &str, numbered: bool) -> String {
+    if pinyin.is_empty() {
+        return String::new();
+    }
+    let syllable = Syllable::parse(pinyin);
+    let final_ = match (syllable.initial, syllable.final_.as_str()) {
+        ("z" | "r", "i") => String::new(),
+        ("c" | "s", "i") => "z".to_string(),
+        _ if syllable.is_apical() => "r".to_string(),
+        ("b" | "p" | "m" | "f", "o") => "wo".to_string(),
+        ("", "i" | "in" | "ing") => format!("y{}", syllable.final_),
+        ("", "u") => "wu".to_string(),
+        ("", "uen") => "wen".to_string(),
+        (_, "uen") => "wun".to_string(),
+        (_, "ê") => "e".to_string(),
+        (_, final_) => {
+            let final_ = final_.replace("ao", "au").replace("ong", "ung");
+            let final_ = match final_.strip_prefix('ü') {
+                Some(rest) if rest.starts_with(['a', 'e']) => format!("yw{rest}"),
+                Some(rest) => format!("yu{rest}"),
+                None => final_,
+            };
+            let medial = match final_.as_bytes() {
+                [b'i', b'a' | b'e' | b'o' | b'u', ..] => Some('y'),
+                [b'u', b'a' | b'e' | b'o', ..] => Some('w'),
+                _ => None,
+            };
+            match medial {
+                Some(medial) => format!("{medial}{}", &final_[1..]),
+                None => final_,
+            }
+        }
+    };
+    let initial = match syllable.initial {
+        "q" => "ch",
+        // 韵母已经以 y 开头时 x 只写作 s，如 syau、sywe
+        "x" if final_.starts_with('y') => "s",
+        "x" => "sy",
+        "zh" => "j",
+        "z" => "dz",
+        "c" => "ts",
+        initial => initial,
+    };
+    let text = format!("{initial}{final_}");
+    let mut result = if numbered {
+        text
+    } else {
+        put_tone_mark(&text, tone_mark(syllable.tone))
+    };
+    if syllable.erhua {
+        result.push('r');
+    }
+    if numbered && syllable.tone > 0 {
+        result.push(char::from(b'0' + syllable.tone));
+    }
+    result
+}
+
+/// 注音符号第二式，`marked` 为假时不标注声调
+#[cfg(feature = "mps2")]
+pub fn mps2(pinyin: &str, marked: bool) -> String {
+    if pinyin.is_empty() {
+        return String::new();
+    }
+    let syllable = Syllable::parse(pinyin);
+    let initial = match syllable.initial {
+        "q" => "ch",
+        "x" => "sh",
+        "zh" => "j",
+        "z" => "tz",
+        "c" => "ts",
+        initial => initial,
+    };
+    let final_ = match (syllable.initial, syllable.final_.as_str()) {
+        ("z" | "r", "i") => String::new(),
+        ("c" | "s", "i") => "z".to_string(),
+        _ if syllable.is_apical() => "r".to_string(),
+        (_, final_) => {
+            let final_ = final_
+                .replace("ao", "au")
+                .replace("ong", "ung")
+                .replace('ü', "iu");
+            if syllable.initial.is_empty() {
+                zero_initial(&final_)
+            } else {
+                final_
+            }
+        }
+    };
+    let text = format!("{initial}{final_}");
+    let mut result = if marked {
+        put_tone_mark(&text, tone_mark(syllable.tone))
+    } else {
+        text
+    };
+    if syllable.erhua {
+        result.push('r');
+    }
+    result
+}
+
+/// 通用拼音，`marked` 为假时不标注声调
+///
+/// 第一声不标注，轻声在主要元音上标注 `˚`。
+#[cfg(feature = "tongyong")]
+pub fn tongyong(pinyin: &str, marked: bool) -> String {
+    if pinyin.is_empty() {
+        return String::new();
+    }
+    let syllable = Syllable::parse(pinyin);
+    let initial = match syllable.initial {
+        "q" => "c",
+        "x" => "s",
+        "zh" => "jh",
+        initial => initial,
+    };
+    let final_ = match (syllable.initial, syllable.final_.as_str()) {
+        _ if syllable.is_apical() => "ih".to_string(),
+        ("b" | "p" | "m" | "f", "eng") => "ong".to_string(),
+        ("", "uen") => "wun".to_string(),
+        ("", "ueng") => "wong".to_string(),
+        ("", final_) => zero_initial(&final_.replace('ü', "yu")),
+        (_, "uen") => "un".to_string(),
+        (_, final_) => final_.replace("iong", "yong").replace('ü', "yu"),
+    };
+    let text = format!("{initial}{final_}");
+    let mark = match syllable.tone {
+        0 => Some('\u{30A}'),
+        1 => None,
+        tone => tone_mark(tone),
+    };
+    let mut result = if marked {
+        put_tone_mark(&text, mark)
+    } else {
+        text
+    };
+    if syllable.erhua {
+        result.push('r');
+    }
+    result
+}
+
+/// 零声母音节的拼写：单独的 i、in、ing 前加 y，单独的 u 前加 w，
+/// 其它以 i、u 开头的韵母将其改写为 y、w
+#[cfg(any(feature = "mps2", feature = "tongyong"))]
+fn zero_initial(final_: &str) -> String {
+    match final_ {
+        "i" | "in" | "ing" => format!("y{final_}"),
+        "u" => "wu".to_string(),
+        _ if final_.starts_with('i') => format!("y{}", &final_[1..]),
+        _ if final_.starts_with('u') => format!("w{}", &final_[1..]),
+        _ => final_.to_string(),
+    }
+}
+
+/// 西里尔字母转写（巴拉第系统）
+#[cfg(feature = "cyrillic")]
+pub fn cyrillic(pinyin: &str) -> String {
+    if pinyin.is_empty() {
+        return String::new();
+    }
+    let syllable = Syllable::parse(pinyin);
+    let initial = match syllable.initial {
+        "b" => "б",
+        "p" => "п",
+        "m" => "м",
+        "f" => "ф",
+        "d" => "д",
+        "t" => "т",
+        "n" => "н",
+        "l" => "л",
+        "g" => "г",
+        "k" => "к",
+        "h" => "х",
+        "j" | "z" => "цз",
+        "q" | "c" => "ц",
+        "x" | "s" => "с",
+        "zh" => "чж",
+        "ch" => "ч",
+        "sh" => "ш",
+        "r" => "ж",
+        "ng" => "нг",
+        _ => "",
+    };
+    let final_ = match (syllable.initial, syllable.final_.as_str()) {
+        ("z" | "c" | "s", _) if syllable.is_apical() => "ы",
+        ("", "uo") => "во",
+        ("", "ua") => "ва",
+        ("", "uai") => "вай",
+        ("", "uei") => "вэй",
+        ("", "uan") => "вань",
+        ("", "uen") => "вэнь",
+        ("", "uang") => "ван",
+        ("", "ueng") => "вэн",
+        (_, "a") => "а",
+        (_, "o" | "uo") => "о",
+        (_, "e" | "ê") => "э",
+        (_, "ai") => "ай",
+        (_, "ei") => "эй",
+        (_, "ao") => "ао",
+        (_, "ou") => "оу",
+        (_, "an") => "ань",
+        (_, "en") => "энь",
+        (_, "ang") => "ан",
+        (_, "eng") => "эн",
+        (_, "ong") => "ун",
+        (_, "er") => "эр",
+        (_, "i") => "и",
+        (_, "ia") => "я",
+        (_, "ie") => "е",
+        (_, "iao") => "яо",
+        (_, "iou") => "ю",
+        (_, "ian") => "янь",
+        (_, "in") => "инь",
+        (_, "iang") => "ян",
+        (_, "ing") => "ин",
+        (_, "iong") => "юн",
+        (_, "io") => "ё",
+        (_, "u") => "у",
+        (_, "ua") => "уа",
+        (_, "uai") => "уай",
+        (_, "uei") => "уй",
+        (_, "uan") => "уань",
+        (_, "uen") => "унь",
+        (_, "uang") => "уан",
+        (_, "ü") => "юй",
+        (_, "üe") => "юэ",
+        (_, "üan") => "юань",
+        (_, "ün") => "юнь",
+        (_, "m") => "м",
+        (_, "n") => "н",
+        (_, "ng") => "н",
+        (_, "hm") => "хм",
+        (_, "hng") => "хн",
+        (_, final_) => unreachable!("unknown final {:?} in {:?}", final_, pinyin),
+    };
+    let mut result = format!("{initial}{final_}");
+    if syllable.erhua {
+        result.push('р');
+    }
+    result
+}
+
+/// 韩文转写（外来语标记法中的汉语拼音与韩文对照表）
+///
+/// 韵母以韩文字母序列表示，每个元音开始一个音节，其后的辅音为该音节的收音。
+#[cfg(feature = "hangul")]
+pub fn hangul(pinyin: &str) -> String {
+    if pinyin.is_empty() {
+        return String::new();
+    }
+    let syllable = Syllable::parse(pinyin);
+    let initial = match (syllable.initial, syllable.final_.as_str()) {
+        (_, "hm" | "hng") => 'ㅎ',
+        ("b", _) => 'ㅂ',
+        ("p" | "f", _) => 'ㅍ',
+        ("m", _) => 'ㅁ',
+        ("d", _) => 'ㄷ',
+        ("t", _) => 'ㅌ',
+        ("n", _) => 'ㄴ',
+        ("l" | "r", _) => 'ㄹ',
+        ("g", _) => 'ㄱ',
+        ("k", _) => 'ㅋ',
+        ("h", _) => 'ㅎ',
+        ("j" | "zh", _) => 'ㅈ',
+        ("q" | "ch" | "c", _) => 'ㅊ',
+        ("x" | "sh", _) => 'ㅅ',
+        ("z", _) => 'ㅉ',
+        ("s", _) => 'ㅆ',
+        _ => 'ㅇ',
+    };
+    let zero = syllable.initial.is_empty();
+    let jamo = match syllable.final_.as_str() {
+        _ if syllable.is_apical() => "ㅡ",
+        "a" => "ㅏ",
+        "o" => "ㅗ",
+        "e" => "ㅓ",
+        "ê" => "ㅔ",
+        "ai" => "ㅏㅣ",
+        "ei" => "ㅔㅣ",
+        "ao" => "ㅏㅗ",
+        "ou" => "ㅓㅜ",
+        "an" => "ㅏㄴ",
+        "en" => "ㅓㄴ",
+        "ang" => "ㅏㅇ",
+        "eng" => "ㅓㅇ",
+        "ong" => "ㅜㅇ",
+        "er" => "ㅓㄹ",
+        "i" => "ㅣ",
+        "ia" => "ㅑ",
+        "io" => "ㅛ",
+        "ie" => "ㅖ",
+        "iao" => "ㅑㅗ",
+        "iou" => "ㅠ",
+        "ian" => "ㅖㄴ",
+        "in" => "ㅣㄴ",
+        "iang" => "ㅑㅇ",
+        "ing" => "ㅣㅇ",
+        "iong" => "ㅠㅇ",
+        "u" => "ㅜ",
+        "ua" => "ㅘ",
+        "uo" => "ㅝ",
+        "uai" => "ㅘㅣ",
+        "uei" if zero => "ㅞㅣ",
+        "uei" => "ㅜㅣ",
+        "uan" => "ㅘㄴ",
+        "uen" if zero => "ㅝㄴ",
+        "uen" => "ㅜㄴ",
+        "uang" => "ㅘㅇ",
+        "ueng" => "ㅝㅇ",
+        "ü" => "ㅟ",
+        "üe" => "ㅞ",
+        "üan" => "ㅟㅏㄴ",
+        "ün" => "ㅟㄴ",
+        "m" | "hm" => "ㅡㅁ",
+        "n" => "ㅡㄴ",
+        "ng" | "hng" => "ㅡㅇ",
+        final_ => unreachable!("unknown final {:?} in {:?}", final_, pinyin),
+    };
+    let mut result = compose_hangul(initial, jamo);
+    if syllable.erhua {
+        result.push('얼');
+    }
+    result
+}
+
+/// 将声母和韵母的韩文字母组合为韩文音节
+#[cfg(feature = "hangul")]
+fn compose_hangul(initial: char, jamo: &str) -> String {
+    // 韩文音节的初声，按 Unicode 韩文音节的顺序排列
+    const CHOSEONG: &str = "ㄱㄲㄴㄷㄸㄹㅁㅂㅃㅅㅆㅇㅈㅉㅊㅋㅌㅍㅎ";
+    let mut blocks: Vec<(char, char, u32)> = vec![];
+    for ch in jamo.chars() {
+        match ch {
+            'ㅏ'..='ㅣ' => {
+                // ㅈ、ㅉ、ㅊ 后的 ㅑ、ㅖ、ㅛ、ㅠ 写作 ㅏ、ㅔ、ㅗ、ㅜ
+                let vowel = match (blocks.is_empty(), initial, ch) {
+                    (true, 'ㅈ' | 'ㅉ' | 'ㅊ', 'ㅑ') => 'ㅏ',
+                    (true, 'ㅈ' | 'ㅉ' | 'ㅊ', 'ㅖ') => 'ㅔ',
+                    (true, 'ㅈ' | 'ㅉ' | 'ㅊ', 'ㅛ') => 'ㅗ',
+                    (true, 'ㅈ' | 'ㅉ' | 'ㅊ', 'ㅠ') => 'ㅜ',
+                    _ => ch,
+                };
+                let onset = if blocks.is_empty() { initial } else { 'ㅇ' };
+                blocks.push((onset, vowel, 0));
+            }
+            _ => {
+                let coda = match ch {
+                    'ㄴ' => 4,
+                    'ㄹ' => 8,
+                    'ㅁ' => 16,
+                    'ㅇ' => 21,
+                    _ => unreachable!("unknown final consonant {:?}", ch),
+                };
+                blocks.last_mut().unwrap().2 = coda;
+            }
+        }
+    }
+    blocks
+        .into_iter()
+        .map(|(onset, vowel, coda)| {
+            let onset = CHOSEONG.chars().position(|c| c == onset).unwrap() as u32;
+            let vowel = vowel as u32 - 'ㅏ' as u32;
+            char::from_u32(0xAC00 + (onset * 21 + vowel) * 28 + coda).unwrap()
+        })
+        .collect()
+}
+
+/// 片假名转写
+///
+/// 送气与不送气的声母分别以清音和浊音表示，如 `b` 为 `バ` 行、`p` 为 `パ` 行；
+/// 单元音韵母加长音符号，如 `li` 为 `リー`。
+#[cfg(feature = "katakana")]
+pub fn katakana(pinyin: &str) -> String {
+    if pinyin.is_empty() {
+        return String::new();
+    }
+    let syllable = Syllable::parse(pinyin);
+    // 声母与元音 a、i、u、e、o、ü 拼合的假名
+    let row: [&str; 6] = match syllable.initial {
+        "b" => ["バ", "ビ", "ブ", "ベ", "ボ", ""],
+        "p" => ["パ", "ピ", "プ", "ペ", "ポ", ""],
+        "m" => ["マ", "ミ", "ム", "メ", "モ", ""],
+        "f" => ["ファ", "フィ", "フ", "フェ", "フォ", ""],
+        "d" => ["ダ", "ディ", "ドゥ", "デ", "ド", ""],
+        "t" => ["タ", "ティ", "トゥ", "テ", "ト", ""],
+        "n" => ["ナ", "ニ", "ヌ", "ネ", "ノ", "ニュ"],
+        "l" => ["ラ", "リ", "ル", "レ", "ロ", "リュ"],
+        "g" | "ng" => ["ガ", "ギ", "グ", "ゲ", "ゴ", ""],
+        "k" => ["カ", "キ", "ク", "ケ", "コ", ""],
+        "h" => ["ハ", "ヒ", "フ", "ヘ", "ホ", ""],
+        "j" | "zh" => ["ジャ", "ジ", "ジュ", "ジェ", "ジョ", "ジュ"],
+        "q" | "ch" => ["チャ", "チ", "チュ", "チェ", "チョ", "チュ"],
+        "x" | "sh" => ["シャ", "シ", "シュ", "シェ", "ショ", "シュ"],
+        "r" => ["ラ", "リ", "ル", "レ", "ロ", ""],
+        "z" => ["ザ", "ズ", "ズ", "ゼ", "ゾ", ""],
+        "c" => ["ツァ", "ツ", "ツ", "ツェ", "ツォ", ""],
+        "s" => ["サ", "ス", "ス", "セ", "ソ", ""],
+        _ => ["ア", "イ", "ウ", "エ", "オ", "ユ"],
+    };
+    let [a, i, u, e, o, ü] = row;
+    let zero = syllable.initial.is_empty();
+    // `ャ`、`ョ` 等拗音只能跟在单个假名之后，如 `シャオ`、`ディアオ`
+    let single = i.chars().count() == 1;
+    let result = match syllable.final_.as_str() {
+        _ if syllable.is_apical() => match syllable.initial {
+            "z" | "c" | "s" => format!("{u}ー"),
+            _ => format!("{i}ー"),
+        },
+        "a" => format!("{a}ー"),
+        "o" | "e" => format!("{o}ー"),
+        "ê" => e.to_string(),
+        "ai" => format!("{a}イ"),
+        "ei" => format!("{e}イ"),
+        "ao" => format!("{a}オ"),
+        "ou" => format!("{o}ウ"),
+        "an" | "ang" => format!("{a}ン"),
+        "en" => format!("{e}ン"),
+        "eng" | "ong" => format!("{o}ン"),
+        "er" => "アル".to_string(),
+        "i" => format!("{i}ー"),
+        "ia" if zero => "ヤー".to_string(),
+        "ia" => format!("{i}ア"),
+        "io" => "ヨー".to_string(),
+        "ie" => format!("{i}エ"),
+        "iao" if zero => "ヤオ".to_string(),
+        "iao" if single => format!("{i}ャオ"),
+        "iao" => format!("{i}アオ"),
+        "iou" if zero => "ヨウ".to_string(),
+        "iou" => format!("{i}ウ"),
+        "ian" => format!("{i}エン"),
+        "in" | "ing" => format!("{i}ン"),
+        "iang" if zero => "ヤン".to_string(),
+        "iang" if single => format!("{i}ャン"),
+        "iang" => format!("{i}アン"),
+        "iong" if zero => "ヨン".to_string(),
+        "iong" => format!("{i}ョン"),
+        "u" => format!("{u}ー"),
+        "ua" if zero => "ワー".to_string(),
+        "ua" => format!("{u}ア"),
+        "uo" if zero => "ウォー".to_string(),
+        "uo" => format!("{u}オ"),
+        "uai" if zero => "ワイ".to_string(),
+        "uai" => format!("{u}アイ"),
+        "uei" if zero => "ウェイ".to_string(),
+        "uei" => format!("{u}イ"),
+        "uan" | "uang" if zero => "ワン".to_string(),
+        "uan" | "uang" => format!("{u}アン"),
+        "uen" if zero => "ウェン".to_string(),
+        "uen" => format!("{u}ン"),
+        "ueng" => "ウォン".to_string(),
+        "ü" => format!("{ü}ー"),
+        "üe" => format!("{ü}エ"),
+        "üan" => format!("{ü}エン"),
+        "ün" => format!("{ü}ン"),
+        "m" => "ム".to_string(),
+        "n" | "ng" => "ン".to_string(),
+        "hm" => "フム".to_string(),
+        "hng" => "フン".to_string(),
+        final_ => unreachable!("unknown final {:?} in {:?}", final_, pinyin),
+    };
+    if syllable.erhua {
+        format!("{result}ル")
+    } else {
+        result
+    }
+}
+
+/// 韵母的音系分析：介音、韵腹和韵尾
+#[cfg(feature = "ipa")]
+struct Rhyme<'a> {
+    /// 介音 `i`、`u` 或 `ü`
+    medial: Option<char>,
+    /// 韵腹
+    nucleus: char,
+    /// 韵尾 `i`、`u`、`n` 或 `ng`，`ao` 的韵尾 `o` 也记为 `u`
+    coda: &'a str,
+}
+
+#[cfg(feature = "ipa")]
+impl Rhyme<'_> {
+    fn parse(syllable: &Syllable) -> Rhyme<'_> {
+        let final_ = syllable.final_.as_str();
+        let mut chars = final_.char_indices();
+        let (_, first) = chars.next().unwrap();
+        let rest = chars.as_str();
+        let (medial, rest) = match rest {
+            // 唇音声母之后的 o 实际为 uo
+            _ if final_ == "o" && matches!(syllable.initial, "b" | "p" | "m" | "f") => {
+                (Some('u'), "o")
+            }
+            "" | "n" | "ng" => (None, final_),
+            _ if matches!(first, 'i' | 'u' | 'ü') => (Some(first), rest),
+            _ => (None, final_),
+        };
+        let nucleus = rest.chars().next().unwrap();
+        let coda = match &rest[nucleus.len_utf8()..] {
+            "o" => "u",
+            coda => coda,
+        };
+        Rhyme {
+            medial,
+            nucleus,
+            coda,
+        }
+    }
+
+    fn push_ipa(&self, output: &mut String) {
+        output.push_str(match self.medial {
+            Some('i') => "j",
+            Some('u') => "w",
+            Some('ü') => "ɥ",
+            _ => "",
+        });
+        let front_medial = matches!(self.medial, Some('i' | 'ü'));
+        output.push(match (self.nucleus, self.coda) {
+            ('a', "n") if front_medial => 'ɛ',
+            ('a', "ng" | "u") => 'ɑ',
+            ('o', "ng") => 'ʊ',
+            ('e', "") if front_medial => 'ɛ',
+            ('e', "" | "ng") => 'ɤ',
+            ('e', "n") => 'ə',
+            ('ê', _) => 'ɛ',
+            ('ü', _) => 'y',
+            (nucleus, _) => nucleus,
+        });
+        output.push_str(match self.coda {
+            "i" => "ɪ̯",
+            "u" => "ʊ̯",
+            "ng" => "ŋ",
+            coda => coda,
+        });
+    }
+}
+
+/// 国际音标，`numbered` 为真时以上标数字而不是五度标记字母表示声调
+#[cfg(feature = "ipa")]
+pub fn ipa(pinyin: &str, numbered: bool) -> String {
+    if pinyin.is_empty() {
+        return String::new();
+    }
+    let syllable = Syllable::parse(pinyin);
+    let mut result = String::from(match syllable.initial {
+        "b" => "p",
+        "p" => "pʰ",
+        "d" => "t",
+        "t" => "tʰ",
+        "g" => "k",
+        "k" => "kʰ",
+        "h" => "x",
+        "j" => "tɕ",
+        "q" => "tɕʰ",
+        "x" => "ɕ",
+        "zh" => "ʈʂ",
+        "ch" => "ʈʂʰ",
+        "sh" => "ʂ",
+        "r" => "ʐ",
+        "z" => "ts",
+        "c" => "tsʰ",
+        "ng" => "ŋ",
+        initial => initial,
+    });
+    match syllable.final_.as_str() {
+        _ if syllable.is_apical() && matches!(syllable.initial, "z" | "c" | "s") => {
+            result.push_str("ɹ̩")
+        }
+        _ if syllable.is_apical() => result.push_str("ɻ̩"),
+        "er" => result.push('ɚ'),
+        "m" => result.push_str("m̩"),
+        "n" => result.push_str("n̩"),
+        "ng" => result.push_str("ŋ̍"),
+        "hm" => result.push_str("hm̩"),
+        "hng" => result.push_str("hŋ̍"),
+        _ => Rhyme::parse(&syllable).push_ipa(&mut result),
+    }
+    if syllable.erhua {
+        result.push('˞');
+    }
+    let tones = if numbered {
+        ["", "⁵⁵", "³⁵", "²¹⁴", "⁵¹"]
+    } else {
+        ["", "˥", "˧˥", "˨˩˦", "˥˩"]
+    };
+    result.push_str(tones[usize::from(syllable.tone)]);
+    result
+}
+
+/// 国语罗马字，以拼写变化表示声调，轻声音节以 `.` 开头并使用基本形式
+#[cfg(feature = "gwoyeu_romatzyh")]
+pub fn gwoyeu_romatzyh(pinyin: &str) -> String {
+    if pinyin.is_empty() {
+        return String::new();
+    }
+    let syllable = Syllable::parse(pinyin);
+    let mut result = match syllable.final_.as_str() {
+        "m" | "n" | "ng" | "hm" | "hng" => syllable.final_.clone(),
+        _ => gwoyeu_romatzyh_tonal(&syllable),
+    };
+    if syllable.erhua {
+        result.push('l');
+    }
+    result
+}
+
+#[cfg(feature = "gwoyeu_romatzyh")]
+fn gwoyeu_romatzyh_tonal(syllable: &Syllable) -> String {
+    let initial = match syllable.initial {
+        "q" => "ch",
+        "x" => "sh",
+        "zh" => "j",
+        "z" => "tz",
+        "c" => "ts",
+        initial => initial,
+    };
+    // 第一声的基本形式
+    let basic = match syllable.final_.as_str() {
+        _ if syllable.is_apical() => "y".to_string(),
+        "er" => "el".to_string(),
+        "ê" => "e".to_string(),
+        final_ => final_.replace("ao", "au").replace('ü', "iu"),
+    };
+    let sonorant = matches!(syllable.initial, "m" | "n" | "l" | "r");
+    let final_ = match syllable.tone {
+        0 => return format!(".{initial}{basic}"),
+        // 第一声的次浊声母之后加 h，第二声的次浊声母之后使用基本形式
+        1 if sonorant => return format!("{initial}h{basic}"),
+        1 => basic,
+        2 if sonorant => basic,
+        2 if basic.starts_with(['i', 'u']) => gr_y_w(&basic),
+        2 => {
+            // 在元音之后加 r，如 char、horng、shyr
+            let pos = basic.rfind(['a', 'e', 'i', 'o', 'u', 'y']).unwrap() + 1;
+            format!("{}r{}", &basic[..pos], &basic[pos..])
+        }
+        3 => {
+            let dipping = gr_dipping(&basic);
+            match basic.as_str() {
+                _ if !initial.is_empty() => dipping,
+                "ie" | "uo" => gr_y_w(&dipping),
+                _ if basic.starts_with('i') => format!("y{dipping}"),
+                _ if basic.starts_with('u') => format!("w{dipping}"),
+                _ => dipping,
+            }
+        }
+        _ if initial.is_empty() && (basic == "in" || basic == "ing") => {
+            gr_falling(&format!("y{basic}"))
+        }
+        _ if initial.is_empty() && basic.starts_with(['i', 'u']) => gr_falling(&gr_y_w(&basic)),
+        _ => gr_falling(&basic),
+    };
+    format!("{initial}{final_}")
+}
+
+/// 将开头的 i、u 改写为 y、w，单独的 i、u 写作 yi、wu
+#[cfg(feature = "gwoyeu_romatzyh")]
+fn gr_y_w(text: &str) -> String {
+    match text {
+        "i" => "yi".to_string(),
+        "u" => "wu".to_string(),
+        _ if text.starts_with('i') => format!("y{}", &text[1..]),
+        _ if text.starts_with('u') => format!("w{}", &text[1..]),
+        _ => text.to_string(),
+    }
+}
+
+/// 第三声：介音 i、u 改为 e、o，韵尾 i、u 在 a 之后改为 e、o，其余重写主要元音
+#[cfg(feature = "gwoyeu_romatzyh")]
+fn gr_dipping(basic: &str) -> String {
+    let bytes = basic.as_bytes();
+    match basic {
+        "ai" => "ae".to_string(),
+        "au" => "ao".to_string(),
+        "ie" => "iee".to_string(),
+        "uo" => "uoo".to_string(),
+        _ if bytes.len() > 1 && b"aeiou".contains(&bytes[1]) && bytes[0] == b'i' => {
+            format!("e{}", &basic[1..])
+        }
+        _ if bytes.len() > 1 && b"aeiou".contains(&bytes[1]) && bytes[0] == b'u' => {
+            format!("o{}", &basic[1..])
+        }
+        _ => format!("{}{}", &basic[..1], basic),
+    }
+}
+
+/// 第四声：韵尾 i、u、n、ng、l 改为 y、w、nn、nq、ll，没有韵尾时加 h
+#[cfg(feature = "gwoyeu_romatzyh")]
+fn gr_falling(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let after_vowel = bytes.len() > 1 && b"aeou".contains(&bytes[bytes.len() - 2]);
+    match bytes.last() {
+        Some(b'i') if after_vowel => format!("{}y", &text[..text.len() - 1]),
+        Some(b'u') if after_vowel => format!("{}w", &text[..text.len() - 1]),
+        Some(b'n') => format!("{text}n"),
+        Some(b'l') => format!("{text}l"),
+        Some(b'g') => format!("{}q", &text[..text.len() - 1]),
+        _ => format!("{text}h"),
+    }
+}
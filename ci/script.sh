@@ -17,3 +17,25 @@ cargo test --no-default-features --features=plain,heteronym
 cargo test --no-default-features --features=with_tone,heteronym
 cargo test --no-default-features --features=with_tone_num,heteronym
 cargo test --no-default-features --features=with_tone_num_end,heteronym
+
+cargo test --features=place_name
+cargo test --no-default-features --features=place_name
+cargo test --features=erhua
+cargo test --features=neutral_tone
+cargo test --features=erhua,neutral_tone
+cargo test --no-default-features --features=particle_a
+cargo test --features=particle_a,erhua
+cargo test --features=tone_sandhi
+cargo test --no-default-features --features=with_tone,tone_sandhi
+cargo test --features=termcolor
+cargo test --features=yale
+cargo test --features=gwoyeu_romatzyh
+cargo test --features=mps2,tongyong
+cargo test --features=cyrillic
+cargo test --features=ipa
+cargo test --features=hangul,katakana
+cargo test --features=braille
+cargo test --no-default-features --features=braille
+cargo test --no-default-features --features=shuangpin
+cargo test --no-default-features --features=phoneme
+cargo test --features=phoneme,erhua
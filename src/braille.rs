@@ -0,0 +1,277 @@
+use crate::{Pinyin, ToPinyin};
+
+/// 盲文方案
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BrailleScheme {
+    /// 现行盲文，一般不标调，只在单独成词的音节后标调
+    ///
+    /// 这是一种简化处理。规范要求在读音容易混淆的地方标调，判断时需要分词和词义，这里不做判断。
+    /// 由于不进行分词，前后都不是汉字的音节视为单独成词，
+    /// 句子中连写的音节全都不标调，即使个别音节按规范应当标调。
+    Current,
+    /// 2018 年的国家通用盲文，除轻声外每个音节都标调
+    ///
+    /// 这是一种简化处理。GF 0019-2018 允许在部分常用字和词中省写声调，这里没有实现这些省写规则，
+    /// 因此会比规范写法多出一些声调符号，但读音不会出错。
+    Common,
+}
+
+/// 盲文渲染器，将汉字转换为 Unicode 盲文点字
+///
+/// 每个音节由声母、韵母和声调三部分的点字组成，
+/// 其中 `zhi`、`chi`、`shi`、`ri`、`zi`、`ci`、`si` 只写声母，`y`、`w` 开头的音节只写韵母。
+/// 连续汉字的音节连写，常用中文标点转换为对应的盲文符号，其它字符原样保留。
+///
+/// *仅在启用 `braille` 特性时可用*
+/// ```
+/// use pinyin::{BrailleRenderer, BrailleScheme};
+/// let renderer = BrailleRenderer::new(BrailleScheme::Common);
+/// assert_eq!(renderer.render("中国"), "⠌⠲⠁⠛⠕⠂");
+/// let renderer = BrailleRenderer::new(BrailleScheme::Current);
+/// assert_eq!(renderer.render("中国"), "⠌⠲⠛⠕");
+/// assert_eq!(renderer.render("好。"), "⠓⠖⠄⠐⠆");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BrailleRenderer {
+    scheme: BrailleScheme,
+}
+
+impl BrailleRenderer {
+    /// 使用给定的盲文方案创建渲染器
+    pub fn new(scheme: BrailleScheme) -> BrailleRenderer {
+        BrailleRenderer { scheme }
+    }
+
+    /// 转换为盲文
+    pub fn render(&self, input: &str) -> String {
+        let pinyins: Vec<_> = input.to_pinyin().collect();
+        let mut output = String::with_capacity(input.len() * 2);
+        for (i, (ch, pinyin)) in input.chars().zip(pinyins.iter()).enumerate() {
+            let pinyin = match pinyin {
+                Some(pinyin) => *pinyin,
+                None => {
+                    match punctuation(ch) {
+                        Some(cells) => output.push_str(cells),
+                        None => output.push(ch),
+                    }
+                    continue;
+                }
+            };
+            push_syllable(&mut output, pinyin);
+            let marked = match self.scheme {
+                BrailleScheme::Current => {
+                    let prev = i.checked_sub(1).and_then(|i| pinyins[i]);
+                    let next = pinyins.get(i + 1).copied().flatten();
+                    prev.is_none() && next.is_none()
+                }
+                BrailleScheme::Common => true,
+            };
+            if marked {
+                output.push_str(TONES[usize::from(pinyin.tone().min(4))]);
+            }
+        }
+        output
+    }
+}
+
+/// 声调符号，依次为轻声（不标）和第一至第四声
+const TONES: [&str; 5] = ["", "⠁", "⠂", "⠄", "⠆"];
+
+fn push_syllable(output: &mut String, pinyin: Pinyin) {
+    let initial = pinyin.initials();
+    let final_ = normalize_final(initial, pinyin.finals_plain());
+    output.push_str(initial_cell(initial));
+    if is_apical(initial, final_) {
+        return;
+    }
+    match final_cells(final_) {
+        Some(cells) => output.push_str(cells),
+        // 没有韵母的音节（如 `m`、`ng`、`hm`），按字母逐个写出声母
+        None => final_.chars().for_each(|ch| {
+            let mut buf = [0; 4];
+            output.push_str(initial_cell(ch.encode_utf8(&mut buf)));
+        }),
+    }
+}
+
+/// 将韵母还原为完整形式：去掉 `y`、`w`，并展开 `iu`、`ui`、`un` 及 `j`、`q`、`x` 后的 `u`
+fn normalize_final<'a>(initial: &str, final_: &'a str) -> &'a str {
+    let final_ = match final_ {
+        "yi" | "yin" | "ying" | "wu" => &final_[1..],
+        "you" => "iou",
+        "yu" => "ü",
+        "yue" => "üe",
+        "yuan" => "üan",
+        "yun" => "ün",
+        "iu" => "iou",
+        "ui" => "uei",
+        _ => match final_.strip_prefix('y') {
+            Some(rest) => match rest {
+                "a" => "ia",
+                "e" => "ie",
+                "ao" => "iao",
+                "an" => "ian",
+                "ang" => "iang",
+                "ong" => "iong",
+                "o" => "io",
+                _ => final_,
+            },
+            None => match final_.strip_prefix('w') {
+                Some(rest) => match rest {
+                    "a" => "ua",
+                    "o" => "uo",
+                    "ai" => "uai",
+                    "ei" => "uei",
+                    "an" => "uan",
+                    "en" => "uen",
+                    "ang" => "uang",
+                    "eng" => "ueng",
+                    _ => final_,
+                },
+                None => final_,
+            },
+        },
+    };
+    match (initial, final_) {
+        ("j" | "q" | "x", "u") => "ü",
+        ("j" | "q" | "x", "ue") => "üe",
+        ("j" | "q" | "x", "uan") => "üan",
+        ("j" | "q" | "x", "un") => "ün",
+        ("n" | "l", "ue") => "üe",
+        (_, "un") => "uen",
+        _ => final_,
+    }
+}
+
+fn is_apical(initial: &str, final_: &str) -> bool {
+    final_ == "i" && matches!(initial, "zh" | "ch" | "sh" | "r" | "z" | "c" | "s")
+}
+
+/// 声母的点字，`g`、`j`，`k`、`q` 以及 `h`、`x` 分别共用一个符号，由后接的韵母区分
+fn initial_cell(initial: &str) -> &'static str {
+    match initial {
+        "b" => "⠃",
+        "p" => "⠏",
+        "m" => "⠍",
+        "f" => "⠋",
+        "d" => "⠙",
+        "t" => "⠞",
+        "n" => "⠝",
+        "l" => "⠇",
+        "g" | "j" => "⠛",
+        "k" | "q" => "⠅",
+        "h" | "x" => "⠓",
+        "zh" => "⠌",
+        "ch" => "⠟",
+        "sh" => "⠱",
+        "r" => "⠚",
+        "z" => "⠵",
+        "c" => "⠉",
+        "s" => "⠎",
+        _ => "",
+    }
+}
+
+fn final_cells(final_: &str) -> Option<&'static str> {
+    let cells = match final_ {
+        "a" => "⠔",
+        "o" | "e" | "ê" => "⠢",
+        "i" => "⠊",
+        "u" => "⠥",
+        "ü" => "⠬",
+        "er" => "⠗",
+        "ai" => "⠪",
+        "ei" => "⠮",
+        "ao" => "⠖",
+        "ou" => "⠷",
+        "an" => "⠧",
+        "en" => "⠴",
+        "ang" => "⠦",
+        "eng" => "⠼",
+        "ong" => "⠲",
+        "ia" => "⠫",
+        "ie" => "⠑",
+        "iao" => "⠜",
+        "iou" => "⠳",
+        "ian" => "⠩",
+        "in" => "⠣",
+        "iang" => "⠭",
+        "ing" => "⠡",
+        "iong" => "⠹",
+        "io" => "⠊⠢",
+        "ua" => "⠿",
+        "uo" => "⠕",
+        "uai" => "⠽",
+        "uei" => "⠺",
+        "uan" => "⠻",
+        "uen" => "⠒",
+        "uang" => "⠶",
+        "ueng" => "⠥⠼",
+        "üe" => "⠾",
+        "üan" => "⠯",
+        "ün" => "⠸",
+        _ => return None,
+    };
+    Some(cells)
+}
+
+fn punctuation(ch: char) -> Option<&'static str> {
+    let cells = match ch {
+        '。' => "⠐⠆",
+        '，' => "⠐",
+        '、' => "⠈",
+        '；' => "⠰",
+        '：' => "⠤",
+        '？' => "⠐⠄",
+        '！' => "⠰⠂",
+        _ => return None,
+    };
+    Some(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BrailleRenderer, BrailleScheme};
+
+    #[test]
+    fn syllables() {
+        let renderer = BrailleRenderer::new(BrailleScheme::Common);
+        // 整体认读音节只写声母
+        assert_eq!(renderer.render("是字"), "⠱⠆⠵⠆");
+        // y、w 开头的音节只写韵母
+        assert_eq!(renderer.render("一外"), "⠊⠁⠽⠆");
+        assert_eq!(renderer.render("有月"), "⠳⠄⠾⠆");
+        // j、q、x 后的 u 为 ü
+        assert_eq!(renderer.render("学去"), "⠓⠾⠂⠅⠬⠆");
+        assert_eq!(renderer.render("女绿"), "⠝⠬⠄⠇⠬⠆");
+        assert_eq!(renderer.render("对人"), "⠙⠺⠆⠚⠴⠂");
+        assert_eq!(renderer.render("春"), "⠟⠒⠁");
+    }
+
+    #[test]
+    fn tones() {
+        let common = BrailleRenderer::new(BrailleScheme::Common);
+        let current = BrailleRenderer::new(BrailleScheme::Current);
+        assert_eq!(common.render("我的"), "⠕⠄⠙⠢");
+        assert_eq!(current.render("我的"), "⠕⠙⠢");
+        assert_eq!(current.render("我 的"), "⠕⠄ ⠙⠢");
+        assert_eq!(current.render("我，你"), "⠕⠄⠐⠝⠊⠄");
+    }
+
+    #[test]
+    fn sentence() {
+        let common = BrailleRenderer::new(BrailleScheme::Common);
+        let current = BrailleRenderer::new(BrailleScheme::Current);
+        // 们 读轻声，不标调
+        assert_eq!(common.render("我们是中国人。"), "⠕⠄⠍⠴⠱⠆⠌⠲⠁⠛⠕⠂⠚⠴⠂⠐⠆");
+        // 句子中连写的音节都不标调，只有单独成词的 好 标调
+        assert_eq!(current.render("好，我们是中国人。"), "⠓⠖⠄⠐⠕⠍⠴⠱⠌⠲⠛⠕⠚⠴⠐⠆");
+    }
+
+    #[test]
+    fn other_text() {
+        let renderer = BrailleRenderer::new(BrailleScheme::Common);
+        assert_eq!(renderer.render("a！"), "a⠰⠂");
+        assert_eq!(renderer.render(""), "");
+    }
+}
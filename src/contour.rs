@@ -0,0 +1,160 @@
+use crate::Segment;
+use std::fmt;
+
+/// 声调的调值，以五度标记法表示
+///
+/// 可以通过 [`Pinyin::tone_contour`](crate::Pinyin::tone_contour) 得到字的本调，
+/// 或者通过 [`tone_contours`] 得到句子中各音节实际的调值。
+/// ```
+/// use pinyin::ToneContour;
+/// assert_eq!(ToneContour::DIPPING.pitches(), &[2, 1, 4]);
+/// assert_eq!(ToneContour::DIPPING.chao_letters(), "˨˩˦");
+/// assert_eq!(ToneContour::DIPPING.to_string(), "214");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ToneContour {
+    pitches: &'static [u8],
+    letters: &'static str,
+}
+
+impl ToneContour {
+    /// 阴平 55
+    pub const HIGH_LEVEL: ToneContour = ToneContour::new(&[5, 5], "˥");
+    /// 阳平 35
+    pub const RISING: ToneContour = ToneContour::new(&[3, 5], "˧˥");
+    /// 上声 214
+    pub const DIPPING: ToneContour = ToneContour::new(&[2, 1, 4], "˨˩˦");
+    /// 去声 51
+    pub const FALLING: ToneContour = ToneContour::new(&[5, 1], "˥˩");
+    /// 半上声 21，上声在非上声音节前的读法
+    pub const HALF_THIRD: ToneContour = ToneContour::new(&[2, 1], "˨˩");
+    /// 轻声，调值取决于前一个音节，单独的轻声没有调值
+    pub const NEUTRAL: ToneContour = ToneContour::new(&[], "");
+
+    const fn new(pitches: &'static [u8], letters: &'static str) -> ToneContour {
+        ToneContour { pitches, letters }
+    }
+
+    /// 返回声调的本调，`tone` 与 [`Pinyin::tone`](crate::Pinyin::tone) 相同
+    pub(crate) fn from_tone(tone: u8) -> ToneContour {
+        match tone {
+            1 => ToneContour::HIGH_LEVEL,
+            2 => ToneContour::RISING,
+            3 => ToneContour::DIPPING,
+            4 => ToneContour::FALLING,
+            _ => ToneContour::NEUTRAL,
+        }
+    }
+
+    /// 调值中依次经过的音高，`1` 最低，`5` 最高
+    pub fn pitches(self) -> &'static [u8] {
+        self.pitches
+    }
+
+    /// 赵元任的五度标记字母，平调只写一个字母，如 `55` 为 `˥`
+    pub fn chao_letters(self) -> &'static str {
+        self.letters
+    }
+}
+
+impl fmt::Display for ToneContour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pitches
+            .iter()
+            .try_for_each(|pitch| write!(f, "{pitch}"))
+    }
+}
+
+/// 返回句子中各段文本实际的调值，没有拼音的段为 `None`
+///
+/// 在 [`Converter`](crate::Converter) 转换结果的基础上处理连读变调：
+/// 上声在上声前读 35，在其它音节前读半上声 21，连续多个上声时只有最后一个读本调 214；
+/// 轻声的调值随前一个音节而定，阴平、阳平、上声、去声之后分别为 2、3、4、1。
+/// 一、不 的变调需要在转换时开启 [`Converter::tone_sandhi`](crate::Converter::tone_sandhi)。
+/// ```
+/// use pinyin::{tone_contours, Converter};
+/// let contours = tone_contours(&Converter::new().convert("你好吗？"));
+/// let contours: Vec<_> = contours.iter().map(|c| c.map(|c| c.to_string())).collect();
+/// assert_eq!(contours, vec![Some("35".into()), Some("21".into()), Some("4".into()), None]);
+/// ```
+pub fn tone_contours(segments: &[Segment<'_>]) -> Vec<Option<ToneContour>> {
+    segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            let tone = segment.pinyin?.tone();
+            let prev = i.checked_sub(1).and_then(|i| segments[i].pinyin);
+            let next = segments.get(i + 1).and_then(|next| next.pinyin);
+            let contour = match (tone, next.map(|next| next.tone())) {
+                (3, Some(3)) => ToneContour::RISING,
+                (3, Some(_)) => ToneContour::HALF_THIRD,
+                (0, _) => match prev.map(|prev| prev.tone()) {
+                    Some(1) => ToneContour::new(&[2], "˨"),
+                    Some(2) => ToneContour::new(&[3], "˧"),
+                    Some(3) => ToneContour::new(&[4], "˦"),
+                    Some(4) => ToneContour::new(&[1], "˩"),
+                    _ => ToneContour::NEUTRAL,
+                },
+                _ => ToneContour::from_tone(tone),
+            };
+            Some(contour)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tone_contours, ToneContour};
+    use crate::{Converter, ToPinyin};
+
+    fn contours(input: &str) -> Vec<String> {
+        tone_contours(&Converter::new().convert(input))
+            .into_iter()
+            .map(|contour| contour.map_or("-".to_string(), |c| c.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn citation() {
+        let letters: Vec<_> = "妈麻马骂的"
+            .to_pinyin()
+            .map(|pinyin| pinyin.unwrap().tone_contour().chao_letters())
+            .collect();
+        assert_eq!(letters, vec!["˥", "˧˥", "˨˩˦", "˥˩", ""]);
+        assert_eq!(ToneContour::NEUTRAL.pitches(), &[] as &[u8]);
+        assert_eq!(ToneContour::HALF_THIRD.to_string(), "21");
+    }
+
+    #[test]
+    fn third_tone() {
+        assert_eq!(contours("好"), vec!["214"]);
+        assert_eq!(contours("你好"), vec!["35", "214"]);
+        assert_eq!(contours("我很好"), vec!["35", "35", "214"]);
+        assert_eq!(contours("好人"), vec!["21", "35"]);
+        assert_eq!(contours("好，人"), vec!["214", "-", "35"]);
+    }
+
+    #[test]
+    fn neutral_tone() {
+        assert_eq!(contours("妈的"), vec!["55", "2"]);
+        assert_eq!(contours("人的"), vec!["35", "3"]);
+        assert_eq!(contours("我的"), vec!["21", "4"]);
+        assert_eq!(contours("骂的"), vec!["51", "1"]);
+        assert_eq!(contours("的"), vec![""]);
+    }
+
+    #[test]
+    #[cfg(feature = "tone_sandhi")]
+    fn with_tone_sandhi() {
+        let segments = Converter::new().tone_sandhi(true).convert("不好");
+        assert_eq!(
+            tone_contours(&segments),
+            vec![Some(ToneContour::FALLING), Some(ToneContour::DIPPING)]
+        );
+        let segments = Converter::new().tone_sandhi(true).convert("不是");
+        assert_eq!(
+            tone_contours(&segments),
+            vec![Some(ToneContour::RISING), Some(ToneContour::FALLING)]
+        );
+    }
+}
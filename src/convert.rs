@@ -0,0 +1,252 @@
+#[cfg(feature = "erhua")]
+use crate::erhua::merge_erhua;
+#[cfg(feature = "neutral_tone")]
+use crate::neutral_tone::apply_neutral_tone;
+#[cfg(feature = "particle_a")]
+use crate::particle_a::apply_particle_a;
+#[cfg(feature = "place_name")]
+use crate::place_name::to_pinyin_place_name;
+#[cfg(feature = "tone_sandhi")]
+use crate::tone_sandhi::apply_tone_sandhi;
+use crate::{Pinyin, ToPinyin};
+
+/// 句子级别的拼音转换器
+///
+/// 与逐字转换的 [`ToPinyin`] 不同，转换器可以结合上下文调整读音。
+/// 各项调整默认关闭，需要通过对应的方法开启。
+/// ```
+/// # #[cfg(feature = "plain")] {
+/// use pinyin::Converter;
+/// let result = Converter::new().convert("拼音");
+/// let result = result
+///     .iter()
+///     .map(|segment| (segment.text, segment.pinyin.map(|p| p.plain())))
+///     .collect::<Vec<_>>();
+/// assert_eq!(result, vec![("拼", Some("pin")), ("音", Some("yin"))]);
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Converter {
+    #[cfg(feature = "erhua")]
+    erhua: bool,
+    #[cfg(feature = "neutral_tone")]
+    neutral_tone: bool,
+    #[cfg(feature = "particle_a")]
+    particle_a: bool,
+    #[cfg(feature = "place_name")]
+    place_name: bool,
+    #[cfg(feature = "tone_sandhi")]
+    tone_sandhi: bool,
+}
+
+/// 转换结果中的一段文本及其拼音
+#[derive(Copy, Clone)]
+pub struct Segment<'a> {
+    /// 原文，通常为一个字符，儿化时包含前一个字和 儿
+    pub text: &'a str,
+    /// 拼音，没有拼音的字符为 `None`
+    pub pinyin: Option<Pinyin>,
+}
+
+impl Converter {
+    /// 返回一个不做任何调整的转换器
+    pub fn new() -> Converter {
+        Converter::default()
+    }
+
+    /// 设置是否将不自成音节的 儿 合并到前一个音节之中
+    ///
+    /// 如 花儿 转换为 `huār`，玩儿 转换为 `wánr`。
+    /// 女儿、儿子 等词语中自成音节的 儿 不受影响。
+    ///
+    /// *仅在启用 `erhua` 特性时可用*
+    /// ```
+    /// # #[cfg(feature = "with_tone")] {
+    /// use pinyin::Converter;
+    /// let converter = Converter::new().erhua(true);
+    /// let to_vec = |input| {
+    ///     converter
+    ///         .convert(input)
+    ///         .into_iter()
+    ///         .filter_map(|segment| segment.pinyin)
+    ///         .map(|pinyin| pinyin.with_tone())
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(to_vec("玩儿"), vec!["wánr"]);
+    /// assert_eq!(to_vec("女儿"), vec!["nǚ", "ér"]);
+    /// # }
+    /// ```
+    #[cfg(feature = "erhua")]
+    pub fn erhua(mut self, enable: bool) -> Converter {
+        self.erhua = enable;
+        self
+    }
+
+    /// 设置是否按规则将助词、后缀和叠音词的后一个字读作轻声
+    ///
+    /// 拼音数据中记录的是单字的本调，开启后会在以下语境中使用轻声：
+    /// 后缀 们、子（如 桌子），什么 等词中的 么，妈妈、哥哥 等叠音亲属称谓的后一个字，
+    /// 以及动词之后的动态助词 着、了、过。
+    ///
+    /// 没有词性信息，动态助词只在常用的单音节动词和形容词之后识别，如 看过、吃了，
+    /// 他过生日 中作动词的 过 保持本调。了解、着急 等词中的 了、着 改为本调 liǎo、zháo。
+    ///
+    /// *仅在启用 `neutral_tone` 特性时可用*
+    /// ```
+    /// # #[cfg(feature = "with_tone")] {
+    /// use pinyin::Converter;
+    /// let converter = Converter::new().neutral_tone(true);
+    /// let to_vec = |input| {
+    ///     converter
+    ///         .convert(input)
+    ///         .into_iter()
+    ///         .filter_map(|segment| segment.pinyin)
+    ///         .map(|pinyin| pinyin.with_tone())
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(to_vec("妈妈"), vec!["mā", "ma"]);
+    /// assert_eq!(to_vec("去过"), vec!["qù", "guo"]);
+    /// assert_eq!(to_vec("经过"), vec!["jīng", "guò"]);
+    /// # }
+    /// ```
+    #[cfg(feature = "neutral_tone")]
+    pub fn neutral_tone(mut self, enable: bool) -> Converter {
+        self.neutral_tone = enable;
+        self
+    }
+
+    /// 设置是否根据前一个音节改写语气词 啊 的读音
+    ///
+    /// 语气词 啊 受前一音节韵母的影响，分别读作 ya、wa、na、nga 或 ra，
+    /// 如 去啊 读作 qù ya，好啊 读作 hǎo wa，天啊 读作 tiān na。
+    ///
+    /// *仅在启用 `particle_a` 特性时可用*
+    /// ```
+    /// use pinyin::Converter;
+    /// let converter = Converter::new().particle_a(true);
+    /// let to_vec = |input| {
+    ///     converter
+    ///         .convert(input)
+    ///         .into_iter()
+    ///         .filter_map(|segment| segment.pinyin)
+    ///         .map(|pinyin| pinyin.plain())
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(to_vec("好啊"), vec!["hao", "wa"]);
+    /// assert_eq!(to_vec("唱啊"), vec!["chang", "nga"]);
+    /// ```
+    #[cfg(feature = "particle_a")]
+    pub fn particle_a(mut self, enable: bool) -> Converter {
+        self.particle_a = enable;
+        self
+    }
+
+    /// 设置是否对文本中的地名使用地名读音
+    ///
+    /// 开启后先在输入中匹配地名词表，再逐字转换其余部分，如 六安 读作 lù ān，番禺 读作 pān yú。
+    /// 地名词表按最长匹配查找，不考虑词语边界，普通文本中偶尔会有误判，如 家长春节 中的 长春。
+    ///
+    /// *仅在启用 `place_name` 特性时可用*
+    /// ```
+    /// # #[cfg(feature = "with_tone")] {
+    /// use pinyin::Converter;
+    /// let converter = Converter::new().place_name(true);
+    /// let to_vec = |input| {
+    ///     converter
+    ///         .convert(input)
+    ///         .into_iter()
+    ///         .filter_map(|segment| segment.pinyin)
+    ///         .map(|pinyin| pinyin.with_tone())
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(to_vec("去番禺"), vec!["qù", "pān", "yú"]);
+    /// # }
+    /// ```
+    #[cfg(feature = "place_name")]
+    pub fn place_name(mut self, enable: bool) -> Converter {
+        self.place_name = enable;
+        self
+    }
+
+    /// 设置是否按规则改变 一、不 的声调
+    ///
+    /// 一 在第四声前读 yí，在第一、二、三声前读 yì，单用、在词语末尾或者表示序数时读本调 yī，
+    /// 如 一个、一天、第一、十一、一月；不 在第四声前读 bú，如 不是。
+    ///
+    /// *仅在启用 `tone_sandhi` 特性时可用*
+    /// ```
+    /// # #[cfg(feature = "with_tone")] {
+    /// use pinyin::Converter;
+    /// let converter = Converter::new().tone_sandhi(true);
+    /// let to_vec = |input| {
+    ///     converter
+    ///         .convert(input)
+    ///         .into_iter()
+    ///         .filter_map(|segment| segment.pinyin)
+    ///         .map(|pinyin| pinyin.with_tone())
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(to_vec("一个"), vec!["yí", "gè"]);
+    /// assert_eq!(to_vec("一百"), vec!["yì", "bǎi"]);
+    /// assert_eq!(to_vec("第一"), vec!["dì", "yī"]);
+    /// assert_eq!(to_vec("不是"), vec!["bú", "shì"]);
+    /// # }
+    /// ```
+    #[cfg(feature = "tone_sandhi")]
+    pub fn tone_sandhi(mut self, enable: bool) -> Converter {
+        self.tone_sandhi = enable;
+        self
+    }
+
+    /// 转换给定的字符串
+    ///
+    /// 返回的各段文本按顺序首尾相接，拼接起来即为原字符串。
+    pub fn convert<'a>(&self, input: &'a str) -> Vec<Segment<'a>> {
+        let pinyin_list: Vec<Option<Pinyin>> = {
+            #[cfg(feature = "place_name")]
+            if self.place_name {
+                to_pinyin_place_name(input).collect()
+            } else {
+                input.to_pinyin().collect()
+            }
+            #[cfg(not(feature = "place_name"))]
+            input.to_pinyin().collect()
+        };
+        #[allow(unused_mut)]
+        let mut segments = input
+            .char_indices()
+            .zip(pinyin_list)
+            .map(|((pos, ch), pinyin)| Segment {
+                text: &input[pos..pos + ch.len_utf8()],
+                pinyin,
+            })
+            .collect::<Vec<_>>();
+        #[cfg(feature = "tone_sandhi")]
+        if self.tone_sandhi {
+            apply_tone_sandhi(&mut segments);
+        }
+        #[cfg(feature = "neutral_tone")]
+        if self.neutral_tone {
+            apply_neutral_tone(input, &mut segments);
+        }
+        #[cfg(feature = "erhua")]
+        if self.erhua {
+            merge_erhua(input, &mut segments);
+        }
+        #[cfg(feature = "particle_a")]
+        if self.particle_a {
+            apply_particle_a(&mut segments);
+        }
+        segments
+    }
+}
+
+/// 判断输入中位于 `pos` 处的字符 `ch` 是否处在给定的某个词语之中
+#[cfg(any(feature = "erhua", feature = "neutral_tone"))]
+pub(crate) fn in_words(input: &str, pos: usize, ch: char, words: &[&str]) -> bool {
+    words.iter().any(|word| {
+        word.match_indices(ch).any(|(offset, _)| {
+            input[..pos].ends_with(&word[..offset]) && input[pos..].starts_with(&word[offset..])
+        })
+    })
+}
@@ -0,0 +1,62 @@
+use crate::ToPinyin;
+
+/// 将字符串转写为西里尔字母（巴拉第系统）
+///
+/// 连续汉字的音节连写为一个词，其它字符原样保留，不处理大小写。
+/// 以 `н` 结尾的音节后接以元音开头的音节时加入隔音符号 `ъ`，如 平安 `пинъань`；
+/// 以 `нь` 结尾的音节保留软音符号，不再另加符号，如 延安 `яньань`。
+///
+/// *仅在启用 `cyrillic` 特性时可用*
+/// ```
+/// use pinyin::to_cyrillic;
+/// assert_eq!(to_cyrillic("中国"), "чжунго");
+/// assert_eq!(to_cyrillic("北京，平安"), "бэйцзин，пинъань");
+/// ```
+pub fn to_cyrillic(input: &str) -> String {
+    let mut output = String::with_capacity(input.len() * 2);
+    let mut prev_syllable = false;
+    for (ch, pinyin) in input.chars().zip(input.to_pinyin()) {
+        match pinyin {
+            Some(pinyin) => {
+                let syllable = pinyin.cyrillic();
+                if prev_syllable && output.ends_with('н') && starts_with_vowel(syllable) {
+                    output.push('ъ');
+                }
+                output.push_str(syllable);
+                prev_syllable = true;
+            }
+            None => {
+                output.push(ch);
+                prev_syllable = false;
+            }
+        }
+    }
+    output
+}
+
+fn starts_with_vowel(syllable: &str) -> bool {
+    syllable.starts_with(['а', 'о', 'у', 'э', 'я', 'е', 'ё', 'ю'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_cyrillic;
+
+    #[test]
+    fn separators() {
+        assert_eq!(to_cyrillic("中原"), "чжунъюань");
+        assert_eq!(to_cyrillic("西安"), "сиань");
+        assert_eq!(to_cyrillic("延安"), "яньань");
+        assert_eq!(to_cyrillic("平 安"), "пин ань");
+        assert_eq!(to_cyrillic(""), "");
+    }
+
+    #[test]
+    fn syllables() {
+        assert_eq!(to_cyrillic("人民"), "жэньминь");
+        assert_eq!(to_cyrillic("四是字"), "сышицзы");
+        assert_eq!(to_cyrillic("学习"), "сюэси");
+        assert_eq!(to_cyrillic("女王"), "нюйван");
+        assert_eq!(to_cyrillic("对外"), "дуйвай");
+    }
+}
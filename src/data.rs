@@ -1,5 +1,7 @@
 #![allow(clippy::unreadable_literal)]
 
+#[cfg(feature = "plain")]
+use crate::PhraseTable;
 use crate::{CharBlock, PinyinData};
 
 pub(crate) static PINYIN_DATA: &[PinyinData] =
@@ -10,3 +12,31 @@ pub(crate) static HETERONYM_TABLE: &[&[u16]] =
     include!(concat!(env!("OUT_DIR"), "/heteronym_table.rs"));
 
 pub(crate) static CHAR_BLOCKS: &[CharBlock] = include!(concat!(env!("OUT_DIR"), "/char_blocks.rs"));
+
+#[cfg(feature = "plain")]
+pub(crate) static SURNAME_TABLE: &PhraseTable =
+    include!(concat!(env!("OUT_DIR"), "/surname_table.rs"));
+
+#[cfg(feature = "place_name")]
+pub(crate) static PLACE_NAME_TABLE: &PhraseTable =
+    include!(concat!(env!("OUT_DIR"), "/place_name_table.rs"));
+
+/// 语气词 啊 的音变 ya、wa、na、nga、ra 在 `PINYIN_DATA` 中的索引
+#[cfg(feature = "particle_a")]
+pub(crate) static PARTICLE_A_TABLE: [u16; 5] =
+    include!(concat!(env!("OUT_DIR"), "/particle_a_table.rs"));
+
+/// 了、着 不作助词时的读音 liǎo、zháo、zhuó 在 `PINYIN_DATA` 中的索引
+#[cfg(feature = "neutral_tone")]
+pub(crate) static FULL_TONE_TABLE: [u16; 3] =
+    include!(concat!(env!("OUT_DIR"), "/full_tone_table.rs"));
+
+/// 一、不 变调后的读音 yí、yì、bú 在 `PINYIN_DATA` 中的索引
+#[cfg(feature = "tone_sandhi")]
+pub(crate) static TONE_SANDHI_TABLE: [u16; 3] =
+    include!(concat!(env!("OUT_DIR"), "/tone_sandhi_table.rs"));
+
+/// 数据中出现的所有不带声调的音节，按字典序排列
+#[cfg(feature = "plain")]
+pub(crate) static SYLLABLE_TABLE: &[&str] =
+    include!(concat!(env!("OUT_DIR"), "/syllable_table.rs"));
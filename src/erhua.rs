@@ -0,0 +1,122 @@
+use crate::convert::in_words;
+use crate::{Pinyin, Segment};
+
+/// 其中的 儿 自成音节、不作儿化处理的词语
+const SYLLABIC_ER_WORDS: &[&str] = &[
+    "儿童",
+    "儿女",
+    "儿子",
+    "儿孙",
+    "儿媳",
+    "儿戏",
+    "儿时",
+    "儿歌",
+    "儿科",
+    "女儿",
+    "男儿",
+    "婴儿",
+    "幼儿",
+    "孤儿",
+    "胎儿",
+    "健儿",
+    "宠儿",
+    "弃儿",
+    "少儿",
+    "患儿",
+    "妻儿",
+    "育儿",
+    "托儿所",
+    "新生儿",
+    "混血儿",
+    "幸运儿",
+];
+
+/// 将不自成音节的 儿 合并到前一个音节之中
+pub(crate) fn merge_erhua<'a>(input: &'a str, segments: &mut Vec<Segment<'a>>) {
+    let mut result: Vec<Segment<'a>> = Vec::with_capacity(segments.len());
+    let mut end = 0;
+    for segment in segments.drain(..) {
+        let start = end;
+        end += segment.text.len();
+        if segment.text == "儿" && !in_words(input, start, '儿', SYLLABIC_ER_WORDS) {
+            let prev = result.last_mut();
+            if let Some((prev, erhua)) = prev.and_then(|prev| {
+                prev.pinyin
+                    .and_then(Pinyin::erhua)
+                    .map(|erhua| (prev, erhua))
+            }) {
+                prev.text = &input[start - prev.text.len()..end];
+                prev.pinyin = Some(erhua);
+                continue;
+            }
+        }
+        result.push(segment);
+    }
+    *segments = result;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Converter, Pinyin};
+
+    fn convert(input: &str, f: fn(Pinyin) -> &'static str) -> Vec<(&str, &'static str)> {
+        Converter::new()
+            .erhua(true)
+            .convert(input)
+            .into_iter()
+            .filter_map(|segment| segment.pinyin.map(|pinyin| (segment.text, f(pinyin))))
+            .collect()
+    }
+
+    #[test]
+    #[cfg(feature = "plain")]
+    fn erhua_plain() {
+        assert_eq!(convert("花儿", Pinyin::plain), vec![("花儿", "huar")]);
+        assert_eq!(
+            convert("一点儿", Pinyin::plain),
+            vec![("一", "yi"), ("点儿", "dianr")]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with_tone")]
+    fn erhua_with_tone() {
+        assert_eq!(convert("玩儿", Pinyin::with_tone), vec![("玩儿", "wánr")]);
+    }
+
+    #[test]
+    #[cfg(feature = "with_tone_num")]
+    fn erhua_with_tone_num() {
+        assert_eq!(
+            convert("玩儿", Pinyin::with_tone_num),
+            vec![("玩儿", "wa2nr")]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with_tone_num_end")]
+    fn erhua_with_tone_num_end() {
+        assert_eq!(
+            convert("玩儿", Pinyin::with_tone_num_end),
+            vec![("玩儿", "wanr2")]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "plain")]
+    fn syllabic_er() {
+        assert_eq!(
+            convert("女儿", Pinyin::plain),
+            vec![("女", "nü"), ("儿", "er")]
+        );
+        assert_eq!(
+            convert("小儿子", Pinyin::plain),
+            vec![("小", "xiao"), ("儿", "er"), ("子", "zi")]
+        );
+        assert_eq!(convert("儿", Pinyin::plain), vec![("儿", "er")]);
+        assert_eq!(
+            convert("花儿儿", Pinyin::plain),
+            vec![("花儿", "huar"), ("儿", "er")]
+        );
+    }
+}
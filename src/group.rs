@@ -0,0 +1,271 @@
+#[cfg(feature = "place_name")]
+use crate::place_name::match_place_name;
+use crate::surname::match_surname;
+use crate::{PhrasePinyinIter, Pinyin, ToPinyin};
+use std::borrow::Cow;
+
+/// 首字符不是汉字或拉丁字母的字符串所在分组的索引
+const OTHER_LETTER: char = '#';
+
+/// 按拼音首字母分组后的一组字符串
+///
+/// *仅在启用 `plain` 特性时可用*
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LetterGroup<T> {
+    /// 分组的索引，`A` 到 `Z` 之一，或者表示其它字符的 `#`
+    pub letter: char,
+    /// 组内的字符串，已按拼音排序
+    pub items: Vec<T>,
+}
+
+/// 将字符串按拼音首字母分组，用于通讯录、城市选择器等的 A-Z 索引
+///
+/// 分组的索引来自首字的 [`Pinyin::first_letter`]，拉丁字母开头的字符串按该字母分组，
+/// 其它字符开头的字符串都归入最后的 `#` 组。各组之内按拼音排序。
+///
+/// 汉字使用常用读音，如 乐山 归入 `L` 组。人名请使用 [`group_names`]。
+///
+/// *仅在启用 `plain` 特性时可用*
+/// ```
+/// # use pinyin::*;
+/// let groups = group_by_first_letter(vec!["上海", "Alice", "乐山", "句容", "123"]);
+/// let groups = groups
+///     .into_iter()
+///     .map(|group| (group.letter, group.items))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     groups,
+///     vec![
+///         ('A', vec!["Alice"]),
+///         ('J', vec!["句容"]),
+///         ('L', vec!["乐山"]),
+///         ('S', vec!["上海"]),
+///         ('#', vec!["123"]),
+///     ]
+/// );
+/// ```
+pub fn group_by_first_letter<I>(items: I) -> Vec<LetterGroup<I::Item>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    group_with(items, |_| None)
+}
+
+/// 将人名按拼音首字母分组，用于通讯录等的 A-Z 索引
+///
+/// 与 [`group_by_first_letter`] 相同，但字符串开头的多音字姓氏按姓氏的读音处理，
+/// 如 单 归入 `S` 组，曾 归入 `Z` 组。
+///
+/// *仅在启用 `plain` 特性时可用*
+/// ```
+/// # use pinyin::*;
+/// let groups = group_names(vec!["张三", "单雄信", "Alice", "曾国藩", "123"]);
+/// let groups = groups
+///     .into_iter()
+///     .map(|group| (group.letter, group.items))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     groups,
+///     vec![
+///         ('A', vec!["Alice"]),
+///         ('S', vec!["单雄信"]),
+///         ('Z', vec!["曾国藩", "张三"]),
+///         ('#', vec!["123"]),
+///     ]
+/// );
+/// ```
+pub fn group_names<I>(items: I) -> Vec<LetterGroup<I::Item>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    group_with(items, match_surname)
+}
+
+/// 将地名按拼音首字母分组，用于城市选择器等的 A-Z 索引
+///
+/// 与 [`group_by_first_letter`] 相同，但字符串开头的地名使用地名读音，如 尉犁 归入 `Y` 组。
+///
+/// *仅在启用 `place_name` 特性时可用*
+/// ```
+/// # use pinyin::*;
+/// let groups = group_place_names(vec!["尉犁", "乐山", "句容"]);
+/// let groups = groups
+///     .into_iter()
+///     .map(|group| (group.letter, group.items))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     groups,
+///     vec![('J', vec!["句容"]), ('L', vec!["乐山"]), ('Y', vec!["尉犁"])]
+/// );
+/// ```
+#[cfg(feature = "place_name")]
+pub fn group_place_names<I>(items: I) -> Vec<LetterGroup<I::Item>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    group_with(items, match_place_name)
+}
+
+/// 按拼音首字母分组，`match_prefix` 用于匹配字符串开头需要使用特殊读音的词
+fn group_with<I>(
+    items: I,
+    match_prefix: impl Fn(&str) -> Option<(&'static str, PhrasePinyinIter)>,
+) -> Vec<LetterGroup<I::Item>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut keyed_items = items
+        .into_iter()
+        .map(|item| {
+            let (letter, key) = sort_key(item.as_ref(), &match_prefix);
+            (letter, key, item)
+        })
+        .collect::<Vec<_>>();
+    keyed_items.sort_by(|(letter_a, key_a, item_a), (letter_b, key_b, item_b)| {
+        // `#` 组排在所有字母之后
+        (*letter_a == OTHER_LETTER, letter_a, key_a, item_a.as_ref()).cmp(&(
+            *letter_b == OTHER_LETTER,
+            letter_b,
+            key_b,
+            item_b.as_ref(),
+        ))
+    });
+
+    let mut groups: Vec<LetterGroup<I::Item>> = vec![];
+    for (letter, _, item) in keyed_items {
+        match groups.last_mut() {
+            Some(group) if group.letter == letter => group.items.push(item),
+            _ => groups.push(LetterGroup {
+                letter,
+                items: vec![item],
+            }),
+        }
+    }
+    groups
+}
+
+/// 计算字符串的分组索引以及用于排序的键
+///
+/// 排序键由每个字的拼音组成，连续的没有拼音的字符使用其小写形式作为一项。
+fn sort_key(
+    input: &str,
+    match_prefix: impl Fn(&str) -> Option<(&'static str, PhrasePinyinIter)>,
+) -> (char, Vec<Cow<'static, str>>) {
+    let mut key: Vec<Cow<'static, str>> = vec![];
+    let mut first_pinyin = None;
+    let mut rest = input;
+    if let Some((prefix, pinyin_list)) = match_prefix(input) {
+        key.extend(pinyin_list.map(|pinyin| {
+            first_pinyin = first_pinyin.or(Some(pinyin));
+            Cow::Borrowed(pinyin.plain())
+        }));
+        rest = &input[prefix.len()..];
+    }
+    let mut in_other = false;
+    for (ch, pinyin) in rest.chars().zip(rest.to_pinyin()) {
+        match pinyin {
+            Some(pinyin) => {
+                if key.is_empty() {
+                    first_pinyin = Some(pinyin);
+                }
+                key.push(Cow::Borrowed(pinyin.plain()));
+                in_other = false;
+            }
+            None => {
+                match key.last_mut() {
+                    Some(last) if in_other => last.to_mut().extend(ch.to_lowercase()),
+                    _ => key.push(Cow::Owned(ch.to_lowercase().collect())),
+                }
+                in_other = true;
+            }
+        }
+    }
+    let letter = match first_pinyin.map(Pinyin::first_letter) {
+        Some("ê") => Some('e'),
+        Some(letter) => letter.chars().next(),
+        None => input.chars().next().filter(char::is_ascii_alphabetic),
+    };
+    let letter = letter.map_or(OTHER_LETTER, |ch| ch.to_ascii_uppercase());
+    (letter, key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{group_by_first_letter, group_names};
+
+    fn letters(items: &[&str]) -> Vec<(char, Vec<String>)> {
+        group_by_first_letter(items.iter().map(|item| item.to_string()))
+            .into_iter()
+            .map(|group| (group.letter, group.items))
+            .collect()
+    }
+
+    fn name_letters(items: &[&str]) -> Vec<(char, Vec<String>)> {
+        group_names(items.iter().map(|item| item.to_string()))
+            .into_iter()
+            .map(|group| (group.letter, group.items))
+            .collect()
+    }
+
+    #[test]
+    fn polyphonic_surname() {
+        assert_eq!(
+            name_letters(&["单雄信"]),
+            vec![('S', vec!["单雄信".to_string()])]
+        );
+        assert_eq!(
+            name_letters(&["曾国藩"]),
+            vec![('Z', vec!["曾国藩".to_string()])]
+        );
+        assert_eq!(name_letters(&["仇"]), vec![('Q', vec!["仇".to_string()])]);
+    }
+
+    #[test]
+    fn city() {
+        assert_eq!(letters(&["句容"]), vec![('J', vec!["句容".to_string()])]);
+        assert_eq!(letters(&["乐山"]), vec![('L', vec!["乐山".to_string()])]);
+        assert_eq!(letters(&["单县"]), vec![('D', vec!["单县".to_string()])]);
+    }
+
+    #[test]
+    fn other_group_is_last() {
+        let groups = letters(&["123", "王五", "_x", "bob"]);
+        let order = groups.iter().map(|(letter, _)| *letter).collect::<Vec<_>>();
+        assert_eq!(order, vec!['B', 'W', '#']);
+        assert_eq!(groups[2].1, vec!["123".to_string(), "_x".to_string()]);
+    }
+
+    #[test]
+    fn sorted_within_group() {
+        let groups = name_letters(&["张三", "Zoe", "曾国藩", "zeng"]);
+        assert_eq!(
+            groups,
+            vec![(
+                'Z',
+                vec![
+                    "zeng".to_string(),
+                    "曾国藩".to_string(),
+                    "张三".to_string(),
+                    "Zoe".to_string(),
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "place_name")]
+    fn place_name() {
+        let groups = super::group_place_names(vec!["尉犁", "乐山", "句容", "单县"]);
+        let letters = groups.iter().map(|group| group.letter).collect::<Vec<_>>();
+        assert_eq!(letters, vec!['J', 'L', 'S', 'Y']);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(letters(&[]).is_empty());
+    }
+}
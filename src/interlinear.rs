@@ -0,0 +1,334 @@
+use crate::{Pinyin, ToPinyin};
+#[cfg(feature = "termcolor")]
+use std::io;
+#[cfg(feature = "termcolor")]
+use termcolor::{ColorSpec, WriteColor};
+
+/// 逐行对照输出中的一行
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Row {
+    Hanzi,
+    Pinyin,
+    Tone,
+}
+
+/// 对照输出中的一列，即一个字或者一段没有拼音的文本
+struct Column {
+    hanzi: String,
+    pinyin: &'static str,
+    tone: &'static str,
+    width: usize,
+}
+
+/// 终端中汉字与拼音上下对齐的逐行对照输出
+///
+/// 每个字及其拼音占一列，列宽取字和拼音在终端中显示宽度的较大值，两者都在列中居中。
+/// 汉字等全角字符按两个字符宽度计算。没有拼音的连续字符合为一列，空白字符用于分隔各列。
+/// 超出宽度限制时换行，各段之间以空行分隔。
+/// ```
+/// # #[cfg(feature = "with_tone")] {
+/// use pinyin::{Interlinear, Pinyin};
+/// let interlinear = Interlinear::new(Pinyin::with_tone);
+/// assert_eq!(
+///     interlinear.render("中国人"),
+///     " 中   国  人\nzhōng guó rén\n"
+/// );
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Interlinear<F> {
+    style: F,
+    width: usize,
+    tone_numbers: bool,
+    #[cfg(feature = "termcolor")]
+    colors: [Option<ColorSpec>; 3],
+}
+
+impl<F> Interlinear<F>
+where
+    F: Fn(Pinyin) -> &'static str,
+{
+    /// 使用给定的拼音风格创建对照输出，默认宽度为 80 列
+    pub fn new(style: F) -> Interlinear<F> {
+        Interlinear {
+            style,
+            width: 80,
+            tone_numbers: false,
+            #[cfg(feature = "termcolor")]
+            colors: [None, None, None],
+        }
+    }
+
+    /// 设置每行的最大显示宽度，单列超过该宽度时独占一行
+    /// ```
+    /// # #[cfg(feature = "plain")] {
+    /// use pinyin::{Interlinear, Pinyin};
+    /// let interlinear = Interlinear::new(Pinyin::plain).width(8);
+    /// assert_eq!(
+    ///     interlinear.render("我们好"),
+    ///     "我 们\nwo men\n\n好\nhao\n"
+    /// );
+    /// # }
+    /// ```
+    pub fn width(mut self, width: usize) -> Interlinear<F> {
+        self.width = width;
+        self
+    }
+
+    /// 设置是否在拼音之下增加一行声调数字，轻声记为 `5`
+    /// ```
+    /// # #[cfg(feature = "with_tone")] {
+    /// use pinyin::{Interlinear, Pinyin};
+    /// let interlinear = Interlinear::new(Pinyin::with_tone).tone_numbers(true);
+    /// assert_eq!(interlinear.render("妈的"), "妈 的\nmā de\n1  5\n");
+    /// # }
+    /// ```
+    pub fn tone_numbers(mut self, enable: bool) -> Interlinear<F> {
+        self.tone_numbers = enable;
+        self
+    }
+
+    /// 设置汉字行的颜色
+    ///
+    /// *仅在启用 `termcolor` 特性时可用*
+    #[cfg(feature = "termcolor")]
+    pub fn hanzi_color(mut self, color: ColorSpec) -> Interlinear<F> {
+        self.colors[0] = Some(color);
+        self
+    }
+
+    /// 设置拼音行的颜色
+    ///
+    /// *仅在启用 `termcolor` 特性时可用*
+    #[cfg(feature = "termcolor")]
+    pub fn pinyin_color(mut self, color: ColorSpec) -> Interlinear<F> {
+        self.colors[1] = Some(color);
+        self
+    }
+
+    /// 设置声调数字行的颜色
+    ///
+    /// *仅在启用 `termcolor` 特性时可用*
+    #[cfg(feature = "termcolor")]
+    pub fn tone_color(mut self, color: ColorSpec) -> Interlinear<F> {
+        self.colors[2] = Some(color);
+        self
+    }
+
+    /// 返回不带颜色的对照文本
+    pub fn render(&self, input: &str) -> String {
+        let mut output = String::new();
+        for (row, line) in self.lines(input) {
+            if row.is_some() {
+                output.push_str(&line);
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    /// 将带颜色的对照文本写入终端
+    ///
+    /// *仅在启用 `termcolor` 特性时可用*
+    /// ```
+    /// # #[cfg(feature = "plain")] {
+    /// use pinyin::{Interlinear, Pinyin};
+    /// use termcolor::{Color, ColorChoice, ColorSpec, StandardStream};
+    /// let interlinear = Interlinear::new(Pinyin::plain)
+    ///     .pinyin_color(ColorSpec::new().set_fg(Some(Color::Cyan)).clone());
+    /// let mut stdout = StandardStream::stdout(ColorChoice::Auto);
+    /// interlinear.write(&mut stdout, "拼音").unwrap();
+    /// # }
+    /// ```
+    #[cfg(feature = "termcolor")]
+    pub fn write<W: WriteColor>(&self, writer: &mut W, input: &str) -> io::Result<()> {
+        for (row, line) in self.lines(input) {
+            let color = row.and_then(|row| self.colors[row as usize].as_ref());
+            match color {
+                Some(color) => {
+                    writer.set_color(color)?;
+                    write!(writer, "{line}")?;
+                    writer.reset()?;
+                }
+                None => write!(writer, "{line}")?,
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    /// 生成各行文本，`None` 表示两段之间的空行
+    fn lines(&self, input: &str) -> Vec<(Option<Row>, String)> {
+        let mut lines = vec![];
+        for (i, block) in self.blocks(input).into_iter().enumerate() {
+            if i > 0 {
+                lines.push((None, String::new()));
+            }
+            for row in self.rows() {
+                let mut line = String::new();
+                for (j, column) in block.iter().enumerate() {
+                    if j > 0 {
+                        line.push(' ');
+                    }
+                    let text = match row {
+                        Row::Hanzi => &column.hanzi,
+                        Row::Pinyin => column.pinyin,
+                        Row::Tone => column.tone,
+                    };
+                    let padding = column.width - display_width(text);
+                    push_spaces(&mut line, padding / 2);
+                    line.push_str(text);
+                    push_spaces(&mut line, padding - padding / 2);
+                }
+                lines.push((Some(row), line.trim_end().to_string()));
+            }
+        }
+        lines
+    }
+
+    fn rows(&self) -> Vec<Row> {
+        if self.tone_numbers {
+            return vec![Row::Hanzi, Row::Pinyin, Row::Tone];
+        }
+        vec![Row::Hanzi, Row::Pinyin]
+    }
+
+    /// 将输入切分为列，并按宽度限制分段，输入中的换行也会开始新的一段
+    fn blocks(&self, input: &str) -> Vec<Vec<Column>> {
+        let mut blocks = vec![];
+        for input_line in input.lines() {
+            let mut block: Vec<Column> = vec![];
+            let mut line_width = 0;
+            for column in self.columns(input_line) {
+                let width = if block.is_empty() {
+                    column.width
+                } else {
+                    line_width + 1 + column.width
+                };
+                if width > self.width && !block.is_empty() {
+                    blocks.push(std::mem::take(&mut block));
+                    line_width = column.width;
+                } else {
+                    line_width = width;
+                }
+                block.push(column);
+            }
+            blocks.push(block);
+        }
+        blocks
+    }
+
+    fn columns(&self, line: &str) -> Vec<Column> {
+        let mut columns: Vec<Column> = vec![];
+        let mut in_text = false;
+        for (ch, pinyin) in line.chars().zip(line.to_pinyin()) {
+            match pinyin {
+                Some(pinyin) => {
+                    let hanzi = ch.to_string();
+                    let text = (self.style)(pinyin);
+                    let width = display_width(&hanzi).max(display_width(text));
+                    columns.push(Column {
+                        hanzi,
+                        pinyin: text,
+                        tone: tone_number(pinyin),
+                        width,
+                    });
+                    in_text = false;
+                }
+                None if ch.is_whitespace() => in_text = false,
+                None => {
+                    match columns.last_mut() {
+                        Some(column) if in_text => column.hanzi.push(ch),
+                        _ => columns.push(Column {
+                            hanzi: ch.to_string(),
+                            pinyin: "",
+                            tone: "",
+                            width: 0,
+                        }),
+                    }
+                    let column = columns.last_mut().unwrap();
+                    column.width = display_width(&column.hanzi);
+                    in_text = true;
+                }
+            }
+        }
+        columns
+    }
+}
+
+/// 音节的声调数字，轻声为 `5`
+fn tone_number(pinyin: Pinyin) -> &'static str {
+    ["5", "1", "2", "3", "4"][usize::from(pinyin.tone())]
+}
+
+fn push_spaces(line: &mut String, count: usize) {
+    line.extend(std::iter::repeat_n(' ', count));
+}
+
+/// 计算文本在终端中的显示宽度，东亚宽字符和全角字符计为 2，组合字符计为 0
+pub(crate) fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+pub(crate) fn char_width(ch: char) -> usize {
+    match u32::from(ch) {
+        0x0300..=0x036F | 0x200B..=0x200F => 0,
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x20000..=0x3FFFD => 2,
+        _ if ch.is_control() => 0,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "plain")]
+mod tests {
+    use super::display_width;
+    use crate::{Interlinear, Pinyin};
+
+    #[test]
+    fn width() {
+        assert_eq!(display_width("中国"), 4);
+        assert_eq!(display_width("zhōng"), 5);
+        assert_eq!(display_width("ê\u{304}"), 1);
+        assert_eq!(display_width("，"), 2);
+    }
+
+    #[test]
+    fn mixed_text() {
+        let interlinear = Interlinear::new(Pinyin::plain);
+        assert_eq!(
+            interlinear.render("学 Rust，好"),
+            "学  Rust， 好\nxue        hao\n"
+        );
+    }
+
+    #[test]
+    fn wrapping() {
+        let interlinear = Interlinear::new(Pinyin::plain).width(10);
+        assert_eq!(
+            interlinear.render("中国人\n你好"),
+            " 中   国\nzhong guo\n\n人\nren\n\n你 好\nni hao\n"
+        );
+        assert_eq!(interlinear.render(""), "");
+    }
+
+    #[test]
+    fn tone_numbers() {
+        let interlinear = Interlinear::new(Pinyin::plain).tone_numbers(true);
+        assert_eq!(
+            interlinear.render("妈的，好"),
+            "妈 的 ， 好\nma de    hao\n1  5      3\n"
+        );
+    }
+}
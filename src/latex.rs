@@ -0,0 +1,166 @@
+use crate::{Pinyin, ToPinyin};
+
+/// LaTeX 注音命令
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LatexCommand {
+    /// `xpinyin` 宏包的 `\xpinyin{字}{zi4}`，拼音宜使用 [`Pinyin::with_tone_num_end`] 风格
+    Xpinyin,
+    /// `ruby` 等宏包的 `\ruby{字}{zì}`，拼音宜使用 [`Pinyin::with_tone`] 风格
+    Ruby,
+}
+
+/// LaTeX 注音输出
+///
+/// 每个有拼音的字生成一条注音命令，其它文本中的 LaTeX 特殊字符会被转义。
+///
+/// 在字后紧跟 `[读音]` 可以指定该字的读音，用于修正逐字查询得到的多音字读音，
+/// 如 `银行[hang2]`。读音按原样输出（特殊字符同样会被转义）。
+/// 如需在汉字之后保留方括号，写作 `\[`。
+/// ```
+/// # #[cfg(feature = "with_tone_num_end")] {
+/// use pinyin::{LatexEmitter, Pinyin};
+/// let emitter = LatexEmitter::new(Pinyin::with_tone_num_end);
+/// assert_eq!(
+///     emitter.emit("行[hang2] 100%"),
+///     r"\xpinyin{行}{hang2} 100\%"
+/// );
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LatexEmitter<F> {
+    style: F,
+    command: LatexCommand,
+}
+
+impl<F> LatexEmitter<F>
+where
+    F: Fn(Pinyin) -> &'static str,
+{
+    /// 使用给定的拼音风格创建输出，默认使用 [`LatexCommand::Xpinyin`]
+    pub fn new(style: F) -> LatexEmitter<F> {
+        LatexEmitter {
+            style,
+            command: LatexCommand::Xpinyin,
+        }
+    }
+
+    /// 设置所用的注音命令
+    /// ```
+    /// # #[cfg(feature = "with_tone")] {
+    /// use pinyin::{LatexCommand, LatexEmitter, Pinyin};
+    /// let emitter = LatexEmitter::new(Pinyin::with_tone).command(LatexCommand::Ruby);
+    /// assert_eq!(emitter.emit("汉字"), r"\ruby{汉}{hàn}\ruby{字}{zì}");
+    /// # }
+    /// ```
+    pub fn command(mut self, command: LatexCommand) -> LatexEmitter<F> {
+        self.command = command;
+        self
+    }
+
+    /// 生成 LaTeX 文本
+    pub fn emit(&self, input: &str) -> String {
+        let mut output = String::with_capacity(input.len() * 4);
+        let mut chars = input.char_indices().zip(input.to_pinyin());
+        while let Some(((i, ch), pinyin)) = chars.next() {
+            let pinyin = match pinyin {
+                Some(pinyin) => pinyin,
+                None => {
+                    if ch == '\\' && input[i + 1..].starts_with('[') {
+                        chars.next();
+                        output.push('[');
+                    } else {
+                        push_escaped(&mut output, ch);
+                    }
+                    continue;
+                }
+            };
+            let rest = &input[i + ch.len_utf8()..];
+            let reading = match parse_override(rest) {
+                Some(reading) => {
+                    // 跳过方括号及其中的读音
+                    chars.nth(reading.chars().count() + 1);
+                    reading
+                }
+                None => (self.style)(pinyin),
+            };
+            output.push_str(match self.command {
+                LatexCommand::Xpinyin => r"\xpinyin{",
+                LatexCommand::Ruby => r"\ruby{",
+            });
+            push_escaped(&mut output, ch);
+            output.push_str("}{");
+            reading.chars().for_each(|ch| push_escaped(&mut output, ch));
+            output.push('}');
+        }
+        output
+    }
+}
+
+/// 解析紧跟在字后的 `[读音]`，返回其中的读音
+fn parse_override(text: &str) -> Option<&str> {
+    let text = text.strip_prefix('[')?;
+    let end = text.find([']', '[', '\n'])?;
+    (end > 0 && text[end..].starts_with(']')).then(|| &text[..end])
+}
+
+fn push_escaped(output: &mut String, ch: char) {
+    match ch {
+        '\\' => output.push_str(r"\textbackslash{}"),
+        '~' => output.push_str(r"\textasciitilde{}"),
+        '^' => output.push_str(r"\textasciicircum{}"),
+        '#' | '$' | '%' | '&' | '_' | '{' | '}' => {
+            output.push('\\');
+            output.push(ch);
+        }
+        _ => output.push(ch),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with_tone_num_end")]
+mod tests {
+    use crate::{LatexEmitter, Pinyin};
+
+    #[test]
+    fn escape() {
+        let emitter = LatexEmitter::new(Pinyin::with_tone_num_end);
+        assert_eq!(
+            emitter.emit(r"a_b {c} #1 $5 & ~^\"),
+            r"a\_b \{c\} \#1 \$5 \& \textasciitilde{}\textasciicircum{}\textbackslash{}"
+        );
+        assert_eq!(emitter.emit(""), "");
+    }
+
+    #[test]
+    fn xpinyin() {
+        let emitter = LatexEmitter::new(Pinyin::with_tone_num_end);
+        assert_eq!(
+            emitter.emit("我们，好！"),
+            r"\xpinyin{我}{wo3}\xpinyin{们}{men}，\xpinyin{好}{hao3}！"
+        );
+    }
+
+    #[test]
+    fn reading_override() {
+        let emitter = LatexEmitter::new(Pinyin::with_tone_num_end);
+        assert_eq!(
+            emitter.emit("还[huan2]书"),
+            r"\xpinyin{还}{huan2}\xpinyin{书}{shu1}"
+        );
+        assert_eq!(emitter.emit("好[x_1]"), r"\xpinyin{好}{x\_1}");
+        // 不完整或者空的方括号按原样输出
+        assert_eq!(emitter.emit("好[]"), r"\xpinyin{好}{hao3}[]");
+        assert_eq!(emitter.emit("好[hao"), r"\xpinyin{好}{hao3}[hao");
+        assert_eq!(emitter.emit(r"好\[1]"), r"\xpinyin{好}{hao3}[1]");
+        assert_eq!(emitter.emit("[1]"), "[1]");
+    }
+
+    #[test]
+    #[cfg(feature = "with_tone")]
+    fn ruby() {
+        use crate::LatexCommand;
+
+        let emitter = LatexEmitter::new(Pinyin::with_tone).command(LatexCommand::Ruby);
+        assert_eq!(emitter.emit("好[hào]"), r"\ruby{好}{hào}");
+    }
+}
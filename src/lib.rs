@@ -1,18 +1,84 @@
 use crate::data::CHAR_BLOCKS;
 use std::convert::TryFrom;
 
+#[cfg(feature = "braille")]
+mod braille;
 #[cfg(feature = "compat")]
 mod compat;
+mod contour;
+mod convert;
+#[cfg(feature = "cyrillic")]
+mod cyrillic;
 mod data;
+#[cfg(feature = "erhua")]
+mod erhua;
+#[cfg(feature = "plain")]
+mod group;
+mod interlinear;
+mod latex;
+mod markdown;
+#[cfg(feature = "plain")]
+mod name;
+#[cfg(feature = "neutral_tone")]
+mod neutral_tone;
+mod normalize;
+#[cfg(feature = "plain")]
+mod orthography;
+#[cfg(feature = "particle_a")]
+mod particle_a;
+#[cfg(feature = "phoneme")]
+mod phoneme;
 mod pinyin;
 #[cfg(feature = "heteronym")]
 mod pinyin_multi;
+#[cfg(feature = "place_name")]
+mod place_name;
+mod ruby;
+#[cfg(feature = "plain")]
+mod segment;
+#[cfg(feature = "shuangpin")]
+mod shuangpin;
+mod subtitle;
+#[cfg(feature = "plain")]
+mod surname;
+mod tone_color;
+#[cfg(feature = "tone_sandhi")]
+mod tone_sandhi;
 
+#[cfg(feature = "braille")]
+pub use crate::braille::{BrailleRenderer, BrailleScheme};
 #[cfg(feature = "compat")]
 pub use crate::compat::*;
+pub use crate::contour::{tone_contours, ToneContour};
+pub use crate::convert::{Converter, Segment};
+#[cfg(feature = "cyrillic")]
+pub use crate::cyrillic::to_cyrillic;
+#[cfg(feature = "place_name")]
+pub use crate::group::group_place_names;
+#[cfg(feature = "plain")]
+pub use crate::group::{group_by_first_letter, group_names, LetterGroup};
+pub use crate::interlinear::Interlinear;
+pub use crate::latex::{LatexCommand, LatexEmitter};
+pub use crate::markdown::MarkdownAnnotator;
+#[cfg(feature = "plain")]
+pub use crate::name::{Name, NameFormat, UmlautStyle};
+pub use crate::normalize::normalize_text;
+#[cfg(feature = "plain")]
+pub use crate::orthography::{to_orthography, Orthography};
+#[cfg(feature = "phoneme")]
+pub use crate::phoneme::{ErhuaPhoneme, PhonemeTokenizer, ZeroInitialPhoneme};
 pub use crate::pinyin::{Pinyin, PinyinStrIter, ToPinyin};
 #[cfg(feature = "heteronym")]
 pub use crate::pinyin_multi::{PinyinMulti, PinyinMultiIter, PinyinMultiStrIter, ToPinyinMulti};
+#[cfg(feature = "place_name")]
+pub use crate::place_name::{romanize_place_name, to_pinyin_place_name, PlaceNamePinyinIter};
+pub use crate::ruby::RubyRenderer;
+#[cfg(feature = "plain")]
+pub use crate::segment::{segment_pinyin, SyllableSpan};
+#[cfg(feature = "shuangpin")]
+pub use crate::shuangpin::{ShuangpinScheme, ZeroInitial};
+pub use crate::subtitle::SubtitleAnnotator;
+pub use crate::tone_color::{ToneColorRenderer, TonePalette};
 
 /// 将给定输入字符串的拼音通过给定映射函数后存入 `Vec` 中
 ///
@@ -34,8 +100,45 @@ struct PinyinData {
     with_tone_num: &'static str,
     #[cfg(feature = "with_tone_num_end")]
     with_tone_num_end: &'static str,
-    #[cfg(feature = "compat")]
+    #[cfg(feature = "yale")]
+    yale: &'static str,
+    #[cfg(feature = "yale")]
+    yale_num: &'static str,
+    #[cfg(feature = "mps2")]
+    mps2: &'static str,
+    #[cfg(feature = "mps2")]
+    mps2_plain: &'static str,
+    #[cfg(feature = "tongyong")]
+    tongyong: &'static str,
+    #[cfg(feature = "tongyong")]
+    tongyong_plain: &'static str,
+    #[cfg(feature = "gwoyeu_romatzyh")]
+    gwoyeu_romatzyh: &'static str,
+    #[cfg(feature = "cyrillic")]
+    cyrillic: &'static str,
+    #[cfg(feature = "ipa")]
+    ipa: &'static str,
+    #[cfg(feature = "ipa")]
+    ipa_num: &'static str,
+    #[cfg(feature = "hangul")]
+    hangul: &'static str,
+    #[cfg(feature = "katakana")]
+    katakana: &'static str,
+    #[cfg(any(
+        feature = "compat",
+        feature = "particle_a",
+        feature = "braille",
+        feature = "phoneme"
+    ))]
     split: usize,
+    /// 声调，`1` 到 `4` 表示四声，`0` 表示轻声
+    tone: u8,
+    /// 儿化形式在 `PINYIN_DATA` 中的索引，零值表示没有儿化形式
+    #[cfg(feature = "erhua")]
+    erhua: u16,
+    /// 轻声形式在 `PINYIN_DATA` 中的索引
+    #[cfg(feature = "neutral_tone")]
+    neutral: u16,
 }
 
 /// 在 [start, end) 之间字符的数据块
@@ -51,6 +154,10 @@ struct CharBlock {
     heteronym: &'static [u16],
 }
 
+/// 词表，由词语及其各字对应的拼音数据索引组成，按词语排序
+#[cfg(feature = "plain")]
+type PhraseTable = [(&'static str, &'static [u16])];
+
 #[inline]
 fn get_block_and_index(ch: char) -> Option<(&'static CharBlock, usize)> {
     let code = u32::from(ch);
@@ -65,3 +172,54 @@ fn get_block_and_index(ch: char) -> Option<(&'static CharBlock, usize)> {
     }
     None
 }
+
+/// 在词表中查找与输入开头匹配的最长词语，返回该词语及其各字的拼音
+#[cfg(feature = "plain")]
+fn match_phrase(
+    table: &'static PhraseTable,
+    input: &str,
+) -> Option<(&'static str, PhrasePinyinIter)> {
+    let first_len = input.chars().next()?.len_utf8();
+    let first = &input[..first_len];
+    // 以同一个字开头的词语在词表中是连续的
+    let start = table.partition_point(|(phrase, _)| *phrase < first);
+    table[start..]
+        .iter()
+        .take_while(|(phrase, _)| phrase.starts_with(first))
+        .filter(|(phrase, _)| input.starts_with(phrase))
+        .max_by_key(|(phrase, _)| phrase.len())
+        .map(|(phrase, indexes)| (*phrase, PhrasePinyinIter(indexes.iter())))
+}
+
+/// *辅助迭代器*，用于迭代词表中一个词语各字的拼音
+#[cfg(feature = "plain")]
+#[derive(Clone)]
+struct PhrasePinyinIter(std::slice::Iter<'static, u16>);
+
+#[cfg(feature = "plain")]
+impl Iterator for PhrasePinyinIter {
+    type Item = Pinyin;
+
+    fn next(&mut self) -> Option<Pinyin> {
+        self.0
+            .next()
+            .map(|idx| Pinyin(&data::PINYIN_DATA[usize::from(*idx)]))
+    }
+}
+
+/// 将一个音节追加到词的末尾
+///
+/// 词的首字母大写，以 `a`、`o`、`e` 开头的非首音节前加隔音符号。
+#[cfg(feature = "plain")]
+fn push_syllable(word: &mut String, pinyin: Pinyin, syllable: &str) {
+    if word.is_empty() {
+        let mut chars = syllable.chars();
+        word.extend(chars.next().into_iter().flat_map(char::to_uppercase));
+        word.push_str(chars.as_str());
+    } else {
+        if matches!(pinyin.first_letter(), "a" | "o" | "e") {
+            word.push('\'');
+        }
+        word.push_str(syllable);
+    }
+}
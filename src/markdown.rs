@@ -0,0 +1,245 @@
+use crate::{Pinyin, RubyRenderer, ToPinyin};
+
+/// Markdown 预处理器，将标记的文本转换为 `<ruby>` 注音 HTML
+///
+/// 默认以 `{` 和 `}` 标记需要注音的文本，如 `{中国}`，也可以通过 [`delimiters`] 使用其它标记。
+/// 只有在同一行内且含有汉字的标记才会被转换，以免误改 `{#id}` 之类的其它语法。
+/// 代码块（包括围栏代码块和缩进代码块）、行内代码以及用 `\` 转义的标记保持不变。
+///
+/// [`delimiters`]: MarkdownAnnotator::delimiters
+/// ```
+/// # #[cfg(feature = "with_tone")] {
+/// use pinyin::{MarkdownAnnotator, Pinyin, RubyRenderer};
+/// let annotator = MarkdownAnnotator::new(RubyRenderer::new(Pinyin::with_tone));
+/// assert_eq!(
+///     annotator.process("# {中国}\n\n`{中国}`\n"),
+///     "# <ruby>中<rt>zhōng</rt></ruby><ruby>国<rt>guó</rt></ruby>\n\n`{中国}`\n"
+/// );
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MarkdownAnnotator<F> {
+    renderer: RubyRenderer<F>,
+    open: String,
+    close: String,
+}
+
+impl<F> MarkdownAnnotator<F>
+where
+    F: Fn(Pinyin) -> &'static str,
+{
+    /// 使用给定的注音渲染器创建预处理器
+    pub fn new(renderer: RubyRenderer<F>) -> MarkdownAnnotator<F> {
+        MarkdownAnnotator {
+            renderer,
+            open: "{".to_string(),
+            close: "}".to_string(),
+        }
+    }
+
+    /// 设置标记注音文本的起止符号
+    /// ```
+    /// # #[cfg(feature = "plain")] {
+    /// use pinyin::{MarkdownAnnotator, Pinyin, RubyRenderer};
+    /// let annotator = MarkdownAnnotator::new(RubyRenderer::new(Pinyin::plain))
+    ///     .delimiters(":pinyin[", "]");
+    /// assert_eq!(
+    ///     annotator.process("[链接](x) :pinyin[字] {字}"),
+    ///     "[链接](x) <ruby>字<rt>zi</rt></ruby> {字}"
+    /// );
+    /// # }
+    /// ```
+    pub fn delimiters(mut self, open: &str, close: &str) -> MarkdownAnnotator<F> {
+        assert!(
+            !open.is_empty() && !close.is_empty(),
+            "delimiters must not be empty"
+        );
+        self.open = open.to_string();
+        self.close = close.to_string();
+        self
+    }
+
+    /// 处理 Markdown 文本
+    pub fn process(&self, input: &str) -> String {
+        let mut output = String::with_capacity(input.len() * 2);
+        let mut text_start = 0;
+        let mut fence: Option<(char, usize)> = None;
+        let mut prev_blank = true;
+        let mut in_indented = false;
+        let mut offset = 0;
+        for line in input.split_inclusive('\n') {
+            let line_start = offset;
+            offset += line.len();
+            let content = line.trim_end_matches(['\n', '\r']);
+            if let Some((fence_char, fence_len)) = fence {
+                if is_closing_fence(content, fence_char, fence_len) {
+                    fence = None;
+                }
+                output.push_str(line);
+                text_start = offset;
+                continue;
+            }
+            let blank = content.trim().is_empty();
+            if let Some(opening) = opening_fence(content) {
+                self.process_text(&mut output, &input[text_start..line_start]);
+                fence = Some(opening);
+                in_indented = false;
+            } else if is_indented(content) && (prev_blank || in_indented) {
+                if !in_indented {
+                    self.process_text(&mut output, &input[text_start..line_start]);
+                }
+                in_indented = true;
+            } else if blank && in_indented {
+                // 缩进代码块中的空行
+            } else {
+                if in_indented {
+                    text_start = line_start;
+                }
+                in_indented = false;
+                prev_blank = blank;
+                continue;
+            }
+            output.push_str(line);
+            text_start = offset;
+            prev_blank = blank;
+        }
+        self.process_text(&mut output, &input[text_start..]);
+        output
+    }
+
+    /// 处理代码块以外的文本，跳过行内代码和转义字符
+    fn process_text(&self, output: &mut String, text: &str) {
+        let mut rest = text;
+        while let Some(ch) = rest.chars().next() {
+            if ch == '\\' {
+                let len = rest[1..].chars().next().map_or(1, |ch| ch.len_utf8() + 1);
+                output.push_str(&rest[..len]);
+                rest = &rest[len..];
+            } else if ch == '`' {
+                let len = code_span_len(rest);
+                output.push_str(&rest[..len]);
+                rest = &rest[len..];
+            } else if let Some(len) = self.annotate(output, rest) {
+                rest = &rest[len..];
+            } else {
+                output.push(ch);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+    }
+
+    /// 如果 `text` 以标记的注音文本开头，则输出注音并返回所消耗的长度
+    fn annotate(&self, output: &mut String, text: &str) -> Option<usize> {
+        let inner = text.strip_prefix(self.open.as_str())?;
+        let end = inner.find(self.close.as_str())?;
+        let span = &inner[..end];
+        if span.contains('\n') || span.to_pinyin().all(|pinyin| pinyin.is_none()) {
+            return None;
+        }
+        output.push_str(&self.renderer.render(span));
+        Some(self.open.len() + end + self.close.len())
+    }
+}
+
+/// 返回围栏代码块的围栏字符和长度
+fn opening_fence(line: &str) -> Option<(char, usize)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let fence_char = trimmed
+        .chars()
+        .next()
+        .filter(|ch| *ch == '`' || *ch == '~')?;
+    let len = trimmed.len() - trimmed.trim_start_matches(fence_char).len();
+    if len < 3 || (fence_char == '`' && trimmed[len..].contains('`')) {
+        return None;
+    }
+    Some((fence_char, len))
+}
+
+fn is_closing_fence(line: &str, fence_char: char, fence_len: usize) -> bool {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return false;
+    }
+    let rest = trimmed.trim_start_matches(fence_char);
+    trimmed.len() - rest.len() >= fence_len && rest.trim().is_empty()
+}
+
+fn is_indented(line: &str) -> bool {
+    (line.starts_with("    ") || line.starts_with('\t')) && !line.trim().is_empty()
+}
+
+/// 返回以反引号开头的行内代码的长度，没有对应的结束反引号时只返回开头反引号的长度
+fn code_span_len(text: &str) -> usize {
+    let ticks = text.len() - text.trim_start_matches('`').len();
+    let mut pos = ticks;
+    while let Some(start) = text[pos..].find('`') {
+        let start = pos + start;
+        let len = text[start..].len() - text[start..].trim_start_matches('`').len();
+        if len == ticks {
+            return start + len;
+        }
+        pos = start + len;
+    }
+    ticks
+}
+
+#[cfg(test)]
+#[cfg(feature = "plain")]
+mod tests {
+    use crate::{MarkdownAnnotator, Pinyin, RubyRenderer};
+
+    fn annotator() -> MarkdownAnnotator<fn(Pinyin) -> &'static str> {
+        MarkdownAnnotator::new(RubyRenderer::new(Pinyin::plain))
+    }
+
+    #[test]
+    fn spans() {
+        let annotator = annotator();
+        assert_eq!(
+            annotator.process("我爱{中国}。"),
+            "我爱<ruby>中<rt>zhong</rt></ruby><ruby>国<rt>guo</rt></ruby>。"
+        );
+        // 不含汉字、跨行以及转义的标记保持不变
+        assert_eq!(annotator.process("## 标题 {#id}"), "## 标题 {#id}");
+        assert_eq!(annotator.process("{中\n国}"), "{中\n国}");
+        assert_eq!(annotator.process(r"\{字}"), r"\{字}");
+        assert_eq!(annotator.process("{字"), "{字");
+        assert_eq!(annotator.process(""), "");
+    }
+
+    #[test]
+    fn inline_code() {
+        let annotator = annotator();
+        assert_eq!(
+            annotator.process("`{字}` ``a ` {字}`` {字}"),
+            "`{字}` ``a ` {字}`` <ruby>字<rt>zi</rt></ruby>"
+        );
+        assert_eq!(annotator.process("`{字}\n{字}`"), "`{字}\n{字}`");
+        assert_eq!(annotator.process("``{字}"), "``<ruby>字<rt>zi</rt></ruby>");
+    }
+
+    #[test]
+    fn code_blocks() {
+        let annotator = annotator();
+        let input = "{字}\n\n```rust\nlet s = \"{字}\";\n```\n{字}\n~~~~\n{字}\n~~~\n~~~~\r\n";
+        assert_eq!(
+            annotator.process(input),
+            "<ruby>字<rt>zi</rt></ruby>\n\n```rust\nlet s = \"{字}\";\n```\n<ruby>字<rt>zi</rt></ruby>\n~~~~\n{字}\n~~~\n~~~~\r\n"
+        );
+        // 未闭合的围栏代码块延续到文末
+        assert_eq!(annotator.process("```\n{字}\n"), "```\n{字}\n");
+    }
+
+    #[test]
+    fn indented_code() {
+        let annotator = annotator();
+        let input = "{字}\n    {字}\n\n    {字}\n\n    {字}\n{字}\n";
+        assert_eq!(
+            annotator.process(input),
+            "<ruby>字<rt>zi</rt></ruby>\n    <ruby>字<rt>zi</rt></ruby>\n\n    {字}\n\n    {字}\n<ruby>字<rt>zi</rt></ruby>\n"
+        );
+    }
+}
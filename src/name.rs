@@ -0,0 +1,240 @@
+use crate::surname::match_surname;
+use crate::{push_syllable, Pinyin, ToPinyin};
+
+/// 罗马化人名时字母 `ü` 的写法
+///
+/// *仅在启用 `plain` 特性时可用*
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UmlautStyle {
+    /// 保留 `ü`，如： `Lü Bin`
+    Umlaut,
+    /// 使用 `yu` 代替 `ü`，护照等证件使用此写法，如： `LYU Bin`
+    Yu,
+    /// 使用 `v` 代替 `ü`，如： `Lv Bin`
+    V,
+}
+
+/// 人名罗马化的格式
+///
+/// *仅在启用 `plain` 特性时可用*
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NameFormat {
+    /// 字母 `ü` 的写法
+    pub umlaut: UmlautStyle,
+    /// 是否将姓全部大写，如： `OUYANG Xiu`
+    pub uppercase_surname: bool,
+    /// 是否带声调
+    ///
+    /// *仅在启用 `with_tone` 特性时可用*
+    #[cfg(feature = "with_tone")]
+    pub with_tone: bool,
+}
+
+impl NameFormat {
+    /// 返回 GB/T 28039《中国人名汉语拼音字母拼写规则》的默认格式，如： `Ouyang Xiu`、`Lü Bin`
+    pub fn new() -> NameFormat {
+        NameFormat {
+            umlaut: UmlautStyle::Umlaut,
+            uppercase_surname: false,
+            #[cfg(feature = "with_tone")]
+            with_tone: false,
+        }
+    }
+
+    /// 返回护照等证件使用的格式，姓全部大写且 `ü` 写作 `yu`，如： `LYU Bin`
+    pub fn passport() -> NameFormat {
+        NameFormat {
+            umlaut: UmlautStyle::Yu,
+            uppercase_surname: true,
+            #[cfg(feature = "with_tone")]
+            with_tone: false,
+        }
+    }
+}
+
+impl Default for NameFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 切分为姓和名的中文人名
+///
+/// *仅在启用 `plain` 特性时可用*
+/// ```
+/// # use pinyin::*;
+/// let name = Name::parse("欧阳修");
+/// assert_eq!((name.surname(), name.given_name()), ("欧阳", "修"));
+/// assert_eq!(name.romanize(&NameFormat::new()), "Ouyang Xiu");
+/// assert_eq!(Name::parse("吕斌").romanize(&NameFormat::passport()), "LYU Bin");
+/// ```
+pub struct Name<'a> {
+    surname: &'a str,
+    given_name: &'a str,
+    surname_pinyin: Vec<Option<Pinyin>>,
+}
+
+impl<'a> Name<'a> {
+    /// 切分人名，优先匹配复姓，否则以第一个字作为姓
+    ///
+    /// 姓使用作为姓氏时的读音，如 单 读作 shàn，仇 读作 qiú。
+    pub fn parse(name: &'a str) -> Name<'a> {
+        let name = name.trim();
+        let (surname, surname_pinyin) = match match_surname(name) {
+            Some((surname, pinyin_list)) => (surname, pinyin_list.map(Some).collect()),
+            None => {
+                let len = name.chars().next().map_or(0, char::len_utf8);
+                let surname = &name[..len];
+                (surname, surname.to_pinyin().collect())
+            }
+        };
+        Name {
+            surname,
+            given_name: name[surname.len()..].trim_start(),
+            surname_pinyin,
+        }
+    }
+
+    /// 姓
+    pub fn surname(&self) -> &'a str {
+        self.surname
+    }
+
+    /// 名
+    pub fn given_name(&self) -> &'a str {
+        self.given_name
+    }
+
+    /// 姓中每个字的拼音
+    pub fn surname_pinyin(&self) -> impl Iterator<Item = Option<Pinyin>> + '_ {
+        self.surname_pinyin.iter().copied()
+    }
+
+    /// 名中每个字的拼音
+    pub fn given_name_pinyin(&self) -> impl Iterator<Item = Option<Pinyin>> + 'a {
+        self.given_name.to_pinyin()
+    }
+
+    /// 按照给定格式罗马化人名
+    ///
+    /// 姓和名分写，复姓和双名连写，各自首字母大写，
+    /// 以 `a`、`o`、`e` 开头的非首音节前加隔音符号，如： `Zhang Xi'an`。
+    /// ```
+    /// # use pinyin::*;
+    /// let format = NameFormat::new();
+    /// assert_eq!(Name::parse("单田芳").romanize(&format), "Shan Tianfang");
+    /// assert_eq!(Name::parse("张西安").romanize(&format), "Zhang Xi'an");
+    /// let format = NameFormat {
+    ///     umlaut: UmlautStyle::V,
+    ///     uppercase_surname: true,
+    ///     ..NameFormat::new()
+    /// };
+    /// assert_eq!(Name::parse("司马光").romanize(&format), "SIMA Guang");
+    /// assert_eq!(Name::parse("吕斌").romanize(&format), "LV Bin");
+    /// ```
+    pub fn romanize(&self, format: &NameFormat) -> String {
+        let mut surname = romanize_word(self.surname, self.surname_pinyin(), format);
+        if format.uppercase_surname {
+            surname = surname.to_uppercase();
+        }
+        let given_name = romanize_word(self.given_name, self.given_name_pinyin(), format);
+        match (surname.is_empty(), given_name.is_empty()) {
+            (_, true) => surname,
+            (true, false) => given_name,
+            (false, false) => format!("{surname} {given_name}"),
+        }
+    }
+}
+
+/// 将一个词的各个音节连写，首字母大写，并在需要时添加隔音符号
+fn romanize_word(
+    word: &str,
+    pinyin_list: impl Iterator<Item = Option<Pinyin>>,
+    format: &NameFormat,
+) -> String {
+    let mut result = String::new();
+    for (ch, pinyin) in word.chars().zip(pinyin_list) {
+        let pinyin = match pinyin {
+            Some(pinyin) => pinyin,
+            None => {
+                result.push(ch);
+                continue;
+            }
+        };
+        push_syllable(&mut result, pinyin, &syllable(pinyin, format));
+    }
+    result
+}
+
+fn syllable(pinyin: Pinyin, format: &NameFormat) -> String {
+    #[cfg(feature = "with_tone")]
+    let text = if format.with_tone {
+        pinyin.with_tone()
+    } else {
+        pinyin.plain()
+    };
+    #[cfg(not(feature = "with_tone"))]
+    let text = pinyin.plain();
+    let mut result = String::with_capacity(text.len());
+    for ch in text.chars() {
+        const UMLAUTS: &[(char, &str, &str)] = &[
+            ('ü', "yu", "v"),
+            ('ǖ', "yū", "v\u{304}"),
+            ('ǘ', "yú", "v\u{301}"),
+            ('ǚ', "yǔ", "v\u{30c}"),
+            ('ǜ', "yù", "v\u{300}"),
+        ];
+        match UMLAUTS.iter().find(|(umlaut, _, _)| *umlaut == ch) {
+            Some((_, yu, _)) if format.umlaut == UmlautStyle::Yu => result.push_str(yu),
+            Some((_, _, v)) if format.umlaut == UmlautStyle::V => result.push_str(v),
+            _ => result.push(ch),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Name, NameFormat};
+
+    #[test]
+    fn surname_reading() {
+        let format = NameFormat::new();
+        assert_eq!(Name::parse("单田芳").romanize(&format), "Shan Tianfang");
+        assert_eq!(Name::parse("仇明").romanize(&format), "Qiu Ming");
+        assert_eq!(Name::parse("解明").romanize(&format), "Xie Ming");
+        assert_eq!(Name::parse("朴明").romanize(&format), "Piao Ming");
+    }
+
+    #[test]
+    fn compound_surname() {
+        let name = Name::parse("尉迟明");
+        assert_eq!(name.surname(), "尉迟");
+        assert_eq!(name.given_name(), "明");
+        assert_eq!(name.romanize(&NameFormat::new()), "Yuchi Ming");
+    }
+
+    #[test]
+    fn umlaut() {
+        let name = Name::parse("吕女");
+        assert_eq!(name.romanize(&NameFormat::new()), "Lü Nü");
+        assert_eq!(name.romanize(&NameFormat::passport()), "LYU Nyu");
+    }
+
+    #[test]
+    #[cfg(feature = "with_tone")]
+    fn with_tone() {
+        let format = NameFormat {
+            with_tone: true,
+            ..NameFormat::new()
+        };
+        assert_eq!(Name::parse("欧阳修").romanize(&format), "Ōuyáng Xiū");
+        assert_eq!(Name::parse("张西安").romanize(&format), "Zhāng Xī'ān");
+    }
+
+    #[test]
+    fn single_char() {
+        assert_eq!(Name::parse("修").romanize(&NameFormat::new()), "Xiu");
+        assert_eq!(Name::parse("").romanize(&NameFormat::new()), "");
+    }
+}
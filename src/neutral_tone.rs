@@ -0,0 +1,213 @@
+use crate::convert::in_words;
+use crate::data::{FULL_TONE_TABLE, PINYIN_DATA};
+use crate::{Pinyin, Segment};
+
+/// 重叠时后一个字读轻声的亲属称谓等用字，如 妈妈、哥哥
+const REDUPLICATED_CHARS: &[char] = &[
+    '爸', '妈', '爷', '奶', '姥', '哥', '姐', '弟', '妹', '叔', '婶', '伯', '姑', '舅', '婆', '公',
+    '嫂', '宝', '娃',
+];
+
+/// 其中的 子 不读轻声的词语
+const FULL_TONE_ZI_WORDS: &[&str] = &[
+    "孔子",
+    "孟子",
+    "庄子",
+    "墨子",
+    "荀子",
+    "老子",
+    "韩非子",
+    "诸子",
+    "君子",
+    "天子",
+    "太子",
+    "王子",
+    "公子",
+    "弟子",
+    "才子",
+    "学子",
+    "游子",
+    "赤子",
+    "男子",
+    "女子",
+    "父子",
+    "母子",
+    "独生子",
+    "电子",
+    "原子",
+    "分子",
+    "质子",
+    "中子",
+    "粒子",
+    "离子",
+    "光子",
+    "量子",
+    "因子",
+    "卵子",
+    "精子",
+    "瓜子",
+    "莲子",
+    "棋子",
+    "子女",
+    "子孙",
+    "子弹",
+    "子宫",
+    "子夜",
+];
+
+/// 常与动态助词 着、了、过 连用的单音节动词和形容词
+///
+/// 没有词性信息，只有跟在这些字之后的 着、了、过 才按助词处理，
+/// 以免 他过生日 中作动词的 过 被误读为轻声。
+const ASPECT_VERBS: &[char] = &[
+    '看', '听', '说', '读', '写', '吃', '喝', '来', '去', '走', '跑', '跳', '飞', '坐', '站', '躺',
+    '睡', '醒', '住', '做', '干', '办', '买', '卖', '送', '给', '拿', '放', '带', '穿', '戴', '脱',
+    '洗', '用', '找', '见', '想', '爱', '怕', '忘', '记', '学', '教', '问', '叫', '喊', '笑', '哭',
+    '唱', '玩', '打', '开', '关', '等', '到', '回', '进', '出', '上', '下', '起', '死', '病', '变',
+    '成', '完', '懂', '信', '试', '换', '搬', '拉', '推', '抱', '拍', '画', '骑', '游', '追', '停',
+    '留', '离', '活', '尝', '遇', '有', '当', '算', '挂', '寄', '剩', '丢', '好', '坏', '大', '小',
+    '多', '少', '高', '低', '长', '短', '红', '黑', '白', '累', '忙', '冷', '热', '快', '慢', '老',
+    '胖', '瘦', '亮', '饱', '饿', '困', '晚', '早', '对', '错', '乱', '远', '近', '美', '贵',
+];
+
+/// 其中的 着 读作 zháo 的词语
+const ZHAO_WORDS: &[&str] = &["着急", "着凉", "着迷", "着火", "睡着"];
+
+/// 其中的 着 读作 zhuó 的词语
+const ZHUO_WORDS: &[&str] = &[
+    "着想",
+    "着手",
+    "着陆",
+    "着落",
+    "着重",
+    "着实",
+    "着装",
+    "沉着",
+    "执着",
+    "衣着",
+    "附着",
+    "穿着打扮",
+];
+
+/// 其中的 了 读作 liǎo 的词语
+const LIAO_WORDS: &[&str] = &[
+    "了解",
+    "了结",
+    "了却",
+    "了然",
+    "了不起",
+    "了不得",
+    "明了",
+    "不了了之",
+    "受不了",
+    "吃不了",
+    "少不了",
+    "免不了",
+    "忘不了",
+];
+
+/// 其中的 过 不是动态助词的词语
+const NON_PARTICLE_GUO_WORDS: &[&str] = &[
+    "过去", "过来", "过年", "过程", "过度", "过分", "过于", "过失", "过错", "过敏", "过滤", "过期",
+    "过节", "过后", "不过", "经过", "通过", "超过", "难过", "错过", "路过", "度过", "越过", "穿过",
+    "胜过", "罪过", "改过", "放过", "透过", "跨过", "闯过", "记过",
+];
+
+/// 按规则将助词、后缀和叠音词的后一个字改为轻声
+///
+/// 着、了 不作助词时改为 zháo、zhuó、liǎo 等读音，因为它们在拼音数据中的常用读音是轻声。
+pub(crate) fn apply_neutral_tone(input: &str, segments: &mut [Segment<'_>]) {
+    let mut end = 0;
+    for i in 0..segments.len() {
+        let start = end;
+        end += segments[i].text.len();
+        let mut chars = segments[i].text.chars();
+        let ch = match (chars.next(), chars.next()) {
+            (Some(ch), None) => ch,
+            _ => continue,
+        };
+        let full_tone = match ch {
+            '了' if in_words(input, start, ch, LIAO_WORDS) => Some(0),
+            '着' if in_words(input, start, ch, ZHAO_WORDS) => Some(1),
+            '着' if in_words(input, start, ch, ZHUO_WORDS) => Some(2),
+            _ => None,
+        };
+        if let Some(idx) = full_tone {
+            let idx = usize::from(FULL_TONE_TABLE[idx]);
+            segments[i].pinyin = Some(Pinyin(&PINYIN_DATA[idx]));
+            continue;
+        }
+        // 这些都只出现在另一个字之后
+        let prev = match i.checked_sub(1).map(|prev| segments[prev]) {
+            Some(prev) if prev.pinyin.is_some() => prev,
+            _ => continue,
+        };
+        let is_neutral = match ch {
+            '们' | '么' => true,
+            '子' => !in_words(input, start, ch, FULL_TONE_ZI_WORDS),
+            '着' | '了' | '过' => {
+                prev.text.starts_with(ASPECT_VERBS)
+                    && !in_words(input, start, ch, NON_PARTICLE_GUO_WORDS)
+            }
+            _ => REDUPLICATED_CHARS.contains(&ch) && prev.text == segments[i].text,
+        };
+        if is_neutral {
+            segments[i].pinyin = segments[i].pinyin.map(|pinyin| pinyin.neutral());
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with_tone")]
+mod tests {
+    use crate::{Converter, Pinyin};
+
+    fn convert(input: &str) -> Vec<&'static str> {
+        Converter::new()
+            .neutral_tone(true)
+            .convert(input)
+            .into_iter()
+            .filter_map(|segment| segment.pinyin.map(Pinyin::with_tone))
+            .collect()
+    }
+
+    #[test]
+    fn suffix() {
+        assert_eq!(convert("我们"), vec!["wǒ", "men"]);
+        assert_eq!(convert("桌子"), vec!["zhuō", "zi"]);
+        assert_eq!(convert("什么"), vec!["shén", "me"]);
+    }
+
+    #[test]
+    fn reduplication() {
+        assert_eq!(convert("妈妈"), vec!["mā", "ma"]);
+        assert_eq!(convert("爸爸"), vec!["bà", "ba"]);
+        assert_eq!(convert("哥哥"), vec!["gē", "ge"]);
+        assert_eq!(convert("妈"), vec!["mā"]);
+    }
+
+    #[test]
+    fn aspect_particle() {
+        assert_eq!(convert("看着"), vec!["kàn", "zhe"]);
+        assert_eq!(convert("吃了"), vec!["chī", "le"]);
+        assert_eq!(convert("去过"), vec!["qù", "guo"]);
+        assert_eq!(convert("经过"), vec!["jīng", "guò"]);
+        assert_eq!(convert("过去"), vec!["guò", "qù"]);
+    }
+
+    #[test]
+    fn main_verb() {
+        // 生、路、急、手 不在测试数据中，只比较前面的字
+        assert_eq!(convert("他过生日")[..2], ["tā", "guò"]);
+        assert_eq!(convert("我过马路")[..3], ["wǒ", "guò", "mǎ"]);
+        assert_eq!(convert("了解"), vec!["liǎo", "jiě"]);
+        assert_eq!(convert("去了解"), vec!["qù", "liǎo", "jiě"]);
+        assert_eq!(convert("着急")[..1], ["zháo"]);
+        assert_eq!(convert("我着手")[..2], ["wǒ", "zhuó"]);
+    }
+
+    #[test]
+    fn word_initial() {
+        assert_eq!(convert("过，过"), vec!["guò", "guò"]);
+    }
+}
@@ -0,0 +1,581 @@
+/// 数字 0 到 9 的汉字
+const DIGITS: [char; 10] = ['零', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+/// 计量单位及其读法，较长的单位排在前面以便优先匹配
+const UNITS: &[(&str, &str)] = &[
+    ("km/h", "千米每小时"),
+    ("km²", "平方千米"),
+    ("m²", "平方米"),
+    ("km", "千米"),
+    ("kg", "千克"),
+    ("mg", "毫克"),
+    ("cm", "厘米"),
+    ("mm", "毫米"),
+    ("ml", "毫升"),
+    ("mL", "毫升"),
+    ("min", "分钟"),
+    ("°C", "摄氏度"),
+    ("℃", "摄氏度"),
+    ("°", "度"),
+    ("m", "米"),
+    ("g", "克"),
+    ("L", "升"),
+    ("h", "小时"),
+    ("s", "秒"),
+];
+
+/// 在它们前面的 2 读作 两，如 两个、两年
+const MEASURE_WORDS: &[char] = &[
+    '个', '只', '本', '件', '条', '张', '次', '位', '天', '周', '岁', '人', '名', '台', '辆', '杯',
+    '块', '斤', '种', '家', '年', '层', '篇', '份', '倍', '项', '所', '座', '架', '套', '双', '对',
+];
+
+/// 将文本中的数字、日期、时间、金额、百分数和计量单位展开为汉字读法
+///
+/// 拼音数据中没有阿拉伯数字和符号，展开后的文本可以继续交给 [`ToPinyin`](crate::ToPinyin)
+/// 或者 [`Converter`](crate::Converter) 转换。支持的格式有：
+///
+/// - 整数和小数：`128` → 一百二十八，`3.14` → 三点一四，`-5` → 负五，`1,000` → 一千；
+///   以 0 开头或者超过十位的数字串逐位读出，如电话号码
+/// - 以点分隔的多段数字：`1.2.3` → 一点二点三，版本号和 IP 地址等逐位读出
+/// - 年份和日期：`2024年` → 二零二四年，`2024-03-15` → 二零二四年三月十五日
+/// - 时间：`10:05` → 十点零五分
+/// - 金额：`¥128.50` → 一百二十八元五角，`$5` → 五美元
+/// - 百分数和分数：`45%` → 百分之四十五，`3/4` → 四分之三
+/// - 计量单位：`3kg` → 三千克，`25℃` → 二十五摄氏度
+///
+/// 数字 2 在 千 之前、位于数字开头的 百、万、亿 之前以及量词和计量单位之前读作 两，
+/// 如 两千、两百万、两个、两千克，其余读作 二，如 二十、十二、二月。
+/// 一 的变调由 [`Converter::tone_sandhi`](crate::Converter) 处理。
+/// ```
+/// # use pinyin::*;
+/// assert_eq!(normalize_text("2024年3月15日"), "二零二四年三月十五日");
+/// assert_eq!(normalize_text("¥128.50"), "一百二十八元五角");
+/// assert_eq!(normalize_text("45%"), "百分之四十五");
+/// assert_eq!(normalize_text("3kg"), "三千克");
+/// assert_eq!(normalize_text("买2个"), "买两个");
+/// ```
+pub fn normalize_text(input: &str) -> String {
+    let original = input.chars().collect::<Vec<_>>();
+    let chars = original
+        .iter()
+        .map(|&ch| to_half_width(ch))
+        .collect::<Vec<_>>();
+    let mut result = String::with_capacity(input.len());
+    let mut i = 0;
+    while i < chars.len() {
+        match expand(&chars, i, &mut result) {
+            Some(end) => i = end,
+            None => {
+                result.push(original[i]);
+                i += 1;
+            }
+        }
+    }
+    result
+}
+
+/// 将全角的数字和符号转换为半角
+fn to_half_width(ch: char) -> char {
+    match ch {
+        '０'..='９' | '．' | '％' | '／' | '：' | '－' => {
+            char::from_u32(u32::from(ch) - 0xfee0).unwrap()
+        }
+        _ => ch,
+    }
+}
+
+/// 一个阿拉伯数字
+struct Number {
+    /// 整数部分的各位数字，已去除千位分隔符
+    int: String,
+    /// 小数部分的各位数字
+    frac: Option<String>,
+    /// 数字之后的位置
+    end: usize,
+}
+
+impl Number {
+    fn value(&self) -> Option<u64> {
+        self.int.parse().ok()
+    }
+}
+
+fn parse_number(chars: &[char], start: usize) -> Option<Number> {
+    let mut int = String::new();
+    let mut i = start;
+    while i < chars.len() {
+        if chars[i].is_ascii_digit() {
+            int.push(chars[i]);
+            i += 1;
+        } else if chars[i] == ','
+            && !int.is_empty()
+            && chars[i + 1..]
+                .iter()
+                .take(3)
+                .filter(|c| c.is_ascii_digit())
+                .count()
+                == 3
+            && !chars.get(i + 4).is_some_and(char::is_ascii_digit)
+        {
+            i += 1;
+        } else {
+            break;
+        }
+    }
+    if int.is_empty() {
+        return None;
+    }
+    let mut frac = None;
+    if chars.get(i) == Some(&'.') && chars.get(i + 1).is_some_and(char::is_ascii_digit) {
+        let digits = chars[i + 1..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .collect::<String>();
+        i += 1 + digits.len();
+        frac = Some(digits);
+    }
+    Some(Number { int, frac, end: i })
+}
+
+/// 尝试展开位于 `start` 处的数字，返回展开部分之后的位置
+fn expand(chars: &[char], start: usize, result: &mut String) -> Option<usize> {
+    let prev = start.checked_sub(1).map(|i| chars[i]);
+    if prev.is_some_and(|c| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+    // 字母或数字之后的 - 不是负号
+    let negative = chars[start] == '-' && !prev.is_some_and(|c| c.is_ascii_alphanumeric());
+    let sign = if negative { "负" } else { "" };
+    let start = start + usize::from(negative);
+    if let Some(currency) = chars.get(start).and_then(|&ch| currency_name(ch)) {
+        let number = parse_number(chars, start + 1)?;
+        result.push_str(sign);
+        push_money(result, &number, currency);
+        return Some(number.end);
+    }
+    let number = parse_number(chars, start)?;
+    let end = number.end;
+    if let Some(end) = expand_dotted(chars, &number, result) {
+        return Some(end);
+    }
+    if !negative {
+        if let Some(end) = expand_date(chars, &number, result) {
+            return Some(end);
+        }
+        if let Some(end) = expand_time(chars, &number, result) {
+            return Some(end);
+        }
+    }
+    let fraction = match chars.get(end) {
+        Some('/') if number.frac.is_none() => {
+            parse_number(chars, end + 1).filter(|n| n.frac.is_none())
+        }
+        _ => None,
+    };
+    match chars.get(end) {
+        Some('年') if !negative && number.int.len() == 4 && number.frac.is_none() => {
+            push_digits(result, &number.int);
+            Some(end)
+        }
+        Some(&ch) if ch == '%' || ch == '‰' => {
+            result.push_str(if ch == '%' { "百分之" } else { "千分之" });
+            result.push_str(sign);
+            push_number(result, &number, false);
+            Some(end + 1)
+        }
+        Some(&ch) if MEASURE_WORDS.contains(&ch) => {
+            result.push_str(sign);
+            push_number(result, &number, true);
+            Some(end)
+        }
+        _ => {
+            result.push_str(sign);
+            if let Some(denominator) = fraction {
+                push_number(result, &denominator, false);
+                result.push_str("分之");
+                push_number(result, &number, false);
+                return Some(denominator.end);
+            }
+            if let Some((len, unit)) = match_unit(chars, end) {
+                push_number(result, &number, true);
+                result.push_str(unit);
+                return Some(end + len);
+            }
+            // 以 0 开头的数字串以及电话号码等较长的数字串逐位读出
+            let is_digit_string = number.frac.is_none()
+                && number.int.len() > 1
+                && (number.int.starts_with('0') || number.int.len() > 10);
+            if is_digit_string {
+                push_digits(result, &number.int);
+            } else {
+                push_number(result, &number, false);
+            }
+            Some(end)
+        }
+    }
+}
+
+/// 将 `1.2.3`、`192.168.1.1` 等以点分隔的多段数字逐位读出，如 一点二点三
+fn expand_dotted(chars: &[char], first: &Number, result: &mut String) -> Option<usize> {
+    let frac = first.frac.as_ref()?;
+    if chars.get(first.end) != Some(&'.') || !chars.get(first.end + 1)?.is_ascii_digit() {
+        return None;
+    }
+    push_digits(result, &first.int);
+    result.push('点');
+    push_digits(result, frac);
+    let mut end = first.end;
+    while chars.get(end) == Some(&'.') && chars.get(end + 1).is_some_and(char::is_ascii_digit) {
+        let digits = chars[end + 1..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .collect::<String>();
+        result.push('点');
+        push_digits(result, &digits);
+        end += 1 + digits.len();
+    }
+    Some(end)
+}
+
+/// 展开 `2024-03-15`、`2024/3/15` 形式的日期
+fn expand_date(chars: &[char], year: &Number, result: &mut String) -> Option<usize> {
+    if year.int.len() != 4 || year.frac.is_some() {
+        return None;
+    }
+    let sep = *chars.get(year.end).filter(|c| matches!(c, '-' | '/'))?;
+    let month = parse_number(chars, year.end + 1).filter(|n| n.int.len() <= 2)?;
+    if chars.get(month.end) != Some(&sep) {
+        return None;
+    }
+    let day = parse_number(chars, month.end + 1).filter(|n| n.int.len() <= 2)?;
+    if month.frac.is_some() || day.frac.is_some() {
+        return None;
+    }
+    let (m, d) = (month.value()?, day.value()?);
+    if !(1..=12).contains(&m) || !(1..=31).contains(&d) {
+        return None;
+    }
+    push_digits(result, &year.int);
+    result.push('年');
+    result.push_str(&cardinal(m, false));
+    result.push('月');
+    result.push_str(&cardinal(d, false));
+    result.push('日');
+    Some(day.end)
+}
+
+/// 展开 `10:05`、`8:30:15` 形式的时间
+fn expand_time(chars: &[char], hour: &Number, result: &mut String) -> Option<usize> {
+    if hour.int.len() > 2 || hour.frac.is_some() || chars.get(hour.end) != Some(&':') {
+        return None;
+    }
+    let two_digits =
+        |start: usize| parse_number(chars, start).filter(|n| n.int.len() == 2 && n.frac.is_none());
+    let minute = two_digits(hour.end + 1)?;
+    let second = match chars.get(minute.end) {
+        Some(':') => Some(two_digits(minute.end + 1)?),
+        _ => None,
+    };
+    let h = hour.value()?;
+    if h > 24 || minute.value()? > 59 || second.as_ref().is_some_and(|s| s.value() > Some(59)) {
+        return None;
+    }
+    result.push_str(&cardinal(h, true));
+    result.push('点');
+    let mut push_part = |part: &Number, unit: char| {
+        let value = part.value().unwrap();
+        if value > 0 {
+            if value < 10 {
+                result.push('零');
+            }
+            result.push_str(&cardinal(value, false));
+            result.push(unit);
+        }
+    };
+    push_part(&minute, '分');
+    let end = match &second {
+        Some(second) => {
+            push_part(second, '秒');
+            second.end
+        }
+        None => minute.end,
+    };
+    Some(end)
+}
+
+fn currency_name(ch: char) -> Option<&'static str> {
+    match ch {
+        '¥' | '￥' => Some("元"),
+        '$' => Some("美元"),
+        '€' => Some("欧元"),
+        '£' => Some("英镑"),
+        _ => None,
+    }
+}
+
+/// 读出金额，人民币读作 元、角、分，其它货币读作小数
+fn push_money(result: &mut String, number: &Number, currency: &str) {
+    let frac = number.frac.as_deref().unwrap_or("");
+    if currency != "元" || frac.len() > 2 {
+        push_number(result, number, true);
+        result.push_str(currency);
+        return;
+    }
+    let mut digits = frac.bytes().map(|b| u64::from(b - b'0'));
+    let jiao = digits.next().unwrap_or(0);
+    let fen = digits.next().unwrap_or(0);
+    // 超出范围的金额逐位读出
+    let yuan = number.value().filter(|_| number.int.len() <= 16);
+    let has_yuan = yuan != Some(0);
+    match yuan {
+        Some(yuan) if has_yuan || (jiao == 0 && fen == 0) => {
+            result.push_str(&cardinal(yuan, true));
+            result.push('元');
+        }
+        Some(_) => {}
+        None => {
+            push_digits(result, &number.int);
+            result.push('元');
+        }
+    }
+    if jiao > 0 {
+        result.push_str(&cardinal(jiao, true));
+        result.push('角');
+    }
+    if fen > 0 {
+        if jiao == 0 && has_yuan {
+            result.push('零');
+        }
+        result.push_str(&cardinal(fen, true));
+        result.push('分');
+    }
+}
+
+/// 匹配位于 `start` 处的计量单位，单位之前可以有一个空格，返回匹配的字符数以及单位的读法
+fn match_unit(chars: &[char], start: usize) -> Option<(usize, &'static str)> {
+    let space = usize::from(chars.get(start) == Some(&' '));
+    let rest = &chars[start + space..];
+    UNITS.iter().find_map(|(unit, name)| {
+        let len = unit.chars().count();
+        let matched = rest.len() >= len
+            && rest.iter().zip(unit.chars()).all(|(a, b)| *a == b)
+            && !rest.get(len).is_some_and(char::is_ascii_alphabetic);
+        matched.then(|| (space + len, *name))
+    })
+}
+
+/// 读出一个整数或小数，`measure` 表示数字之后是量词或者计量单位
+fn push_number(result: &mut String, number: &Number, measure: bool) {
+    match number.value() {
+        Some(value) if number.int.len() <= 16 => {
+            result.push_str(&cardinal(value, measure && number.frac.is_none()))
+        }
+        _ => push_digits(result, &number.int),
+    }
+    if let Some(frac) = &number.frac {
+        result.push('点');
+        push_digits(result, frac);
+    }
+}
+
+/// 逐位读出数字
+fn push_digits(result: &mut String, digits: &str) {
+    result.extend(digits.bytes().map(|b| DIGITS[usize::from(b - b'0')]));
+}
+
+/// 读出一个整数，`measure` 表示数字之后是量词或者计量单位，此时 2 读作 两
+fn cardinal(value: u64, measure: bool) -> String {
+    if value == 0 {
+        return String::from("零");
+    }
+    if value == 2 {
+        return String::from(if measure { "两" } else { "二" });
+    }
+    let mut result = String::new();
+    push_cardinal(&mut result, value, true);
+    // 十到十九读作 十、十一……，而不是 一十、一十一
+    if result.starts_with("一十") {
+        result.replace_range(.."一".len(), "");
+    }
+    result
+}
+
+fn push_cardinal(result: &mut String, value: u64, leading: bool) {
+    const LARGE_UNITS: [(u64, char); 2] = [(100_000_000, '亿'), (10_000, '万')];
+    let mut rest = value;
+    let mut started = false;
+    let mut zero = false;
+    for (unit, name) in LARGE_UNITS {
+        let high = rest / unit;
+        rest %= unit;
+        if high > 0 {
+            if started && (zero || high < 1000) {
+                result.push('零');
+            }
+            if high >= 10_000 {
+                push_cardinal(result, high, leading && !started);
+            } else {
+                push_section(result, high, leading && !started, true);
+            }
+            result.push(name);
+            started = true;
+            zero = false;
+        } else if started {
+            zero = true;
+        }
+    }
+    if rest > 0 {
+        if started && (zero || rest < 1000) {
+            result.push('零');
+        }
+        push_section(result, rest, leading && !started, false);
+    }
+}
+
+/// 读出一个小于一万的数，`leading` 表示这是整个数字的最高一节，`unit_follows` 表示之后是 万 或 亿
+fn push_section(result: &mut String, value: u64, leading: bool, unit_follows: bool) {
+    const SECTION_UNITS: [&str; 4] = ["千", "百", "十", ""];
+    let digits = [value / 1000, value / 100 % 10, value / 10 % 10, value % 10];
+    let mut started = false;
+    let mut zero = false;
+    for (k, &digit) in digits.iter().enumerate() {
+        if digit == 0 {
+            zero = started;
+            continue;
+        }
+        if zero {
+            result.push('零');
+            zero = false;
+        }
+        let liang =
+            digit == 2 && (k == 0 || (leading && !started && (k == 1 || (k == 3 && unit_follows))));
+        result.push(if liang { '两' } else { DIGITS[digit as usize] });
+        result.push_str(SECTION_UNITS[k]);
+        started = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_text;
+
+    #[test]
+    fn cardinal() {
+        assert_eq!(normalize_text("0"), "零");
+        assert_eq!(normalize_text("2"), "二");
+        assert_eq!(normalize_text("10"), "十");
+        assert_eq!(normalize_text("15"), "十五");
+        assert_eq!(normalize_text("115"), "一百一十五");
+        assert_eq!(normalize_text("200"), "两百");
+        assert_eq!(normalize_text("1200"), "一千二百");
+        assert_eq!(normalize_text("1005"), "一千零五");
+        assert_eq!(normalize_text("10050"), "一万零五十");
+        assert_eq!(normalize_text("22000"), "两万两千");
+        assert_eq!(normalize_text("100000"), "十万");
+        assert_eq!(normalize_text("100000001"), "一亿零一");
+        assert_eq!(normalize_text("101000000"), "一亿零一百万");
+        assert_eq!(normalize_text("1,000,000"), "一百万");
+    }
+
+    #[test]
+    fn decimal_and_sign() {
+        assert_eq!(normalize_text("3.14"), "三点一四");
+        assert_eq!(normalize_text("-5"), "负五");
+        assert_eq!(normalize_text("0.5"), "零点五");
+        assert_eq!(normalize_text("a-5"), "a-五");
+    }
+
+    #[test]
+    fn digit_string() {
+        assert_eq!(normalize_text("010"), "零一零");
+        assert_eq!(normalize_text("13800138000"), "一三八零零一三八零零零");
+        assert_eq!(
+            normalize_text("1234567890"),
+            "十二亿三千四百五十六万七千八百九十"
+        );
+    }
+
+    #[test]
+    fn dotted_digits() {
+        assert_eq!(normalize_text("1.2.3"), "一点二点三");
+        assert_eq!(normalize_text("v1.10.0"), "v一点一零点零");
+        assert_eq!(normalize_text("192.168.1.1"), "一九二点一六八点一点一");
+        assert_eq!(normalize_text("版本2.0.1。"), "版本二点零点一。");
+        assert_eq!(normalize_text("3.14."), "三点一四.");
+    }
+
+    #[test]
+    fn date_and_time() {
+        assert_eq!(normalize_text("2024年3月15日"), "二零二四年三月十五日");
+        assert_eq!(normalize_text("2024-03-15"), "二零二四年三月十五日");
+        assert_eq!(normalize_text("2024/12/2"), "二零二四年十二月二日");
+        assert_eq!(normalize_text("2月"), "二月");
+        assert_eq!(normalize_text("2年"), "两年");
+        assert_eq!(normalize_text("10:05"), "十点零五分");
+        assert_eq!(normalize_text("2:30:00"), "两点三十分");
+        assert_eq!(normalize_text("8:00"), "八点");
+    }
+
+    #[test]
+    fn money() {
+        assert_eq!(normalize_text("¥128.50"), "一百二十八元五角");
+        assert_eq!(normalize_text("￥2"), "两元");
+        assert_eq!(normalize_text("¥3.05"), "三元零五分");
+        assert_eq!(
+            normalize_text("¥123456789012345678901.5"),
+            "一二三四五六七八九零一二三四五六七八九零一元五角"
+        );
+        assert_eq!(normalize_text("¥0.25"), "两角五分");
+        assert_eq!(normalize_text("$12.5"), "十二点五美元");
+    }
+
+    #[test]
+    fn percent_and_fraction() {
+        assert_eq!(normalize_text("45%"), "百分之四十五");
+        assert_eq!(normalize_text("-3.5%"), "百分之负三点五");
+        assert_eq!(normalize_text("3/4"), "四分之三");
+        assert_eq!(normalize_text("５０％"), "百分之五十");
+    }
+
+    #[test]
+    fn unit() {
+        assert_eq!(normalize_text("3kg"), "三千克");
+        assert_eq!(normalize_text("2 km"), "两千米");
+        assert_eq!(normalize_text("120km/h"), "一百二十千米每小时");
+        assert_eq!(normalize_text("5min"), "五分钟");
+        assert_eq!(normalize_text("25℃"), "二十五摄氏度");
+        assert_eq!(normalize_text("3kgs"), "三kgs");
+    }
+
+    #[test]
+    fn in_text() {
+        assert_eq!(
+            normalize_text("他买了2本书，花了¥35"),
+            "他买了两本书，花了三十五元"
+        );
+        assert_eq!(normalize_text("没有数字"), "没有数字");
+    }
+
+    #[test]
+    #[cfg(all(feature = "with_tone", feature = "tone_sandhi"))]
+    fn with_converter() {
+        use crate::{Converter, Pinyin};
+
+        let to_pinyin = |input: &str| {
+            Converter::new()
+                .tone_sandhi(true)
+                .convert(&normalize_text(input))
+                .into_iter()
+                .filter_map(|segment| segment.pinyin.map(Pinyin::with_tone))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        assert_eq!(to_pinyin("¥128.50"), "yì bǎi èr shí bā yuán wǔ jiǎo");
+        assert_eq!(to_pinyin("45%"), "bǎi fēn zhī sì shí wǔ");
+        assert_eq!(
+            to_pinyin("2024年3月15日"),
+            "èr líng èr sì nián sān yuè shí wǔ rì"
+        );
+    }
+}
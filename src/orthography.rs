@@ -0,0 +1,317 @@
+#[cfg(feature = "place_name")]
+use crate::place_name::{match_place_name, to_pinyin_place_name};
+use crate::{Name, Pinyin, ToPinyin};
+
+/// 标点符号在拼音文本中的写法及其前后的空格规则
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Punctuation {
+    /// 句末点号，之后的词首字母大写，如 `.`、`?`
+    SentenceEnd(&'static str),
+    /// 句中点号，之前不空格、之后空格，如 `,`、`;`
+    Pause(&'static str),
+    /// 省略号，之前不空格、之后空格，之后的词不因此大写
+    Ellipsis,
+    /// 前引号、前括号，之前空格、之后不空格
+    Opening(&'static str),
+    /// 后引号、后括号，之前不空格、之后空格
+    Closing(&'static str),
+}
+
+impl Punctuation {
+    fn from_char(ch: char) -> Option<Punctuation> {
+        use Punctuation::*;
+        let punctuation = match ch {
+            '。' | '．' | '.' => SentenceEnd("."),
+            '？' | '?' => SentenceEnd("?"),
+            '！' | '!' => SentenceEnd("!"),
+            '…' => Ellipsis,
+            '，' | '、' | ',' => Pause(","),
+            '；' | ';' => Pause(";"),
+            '：' | ':' => Pause(":"),
+            '—' => Pause("—"),
+            '“' | '「' | '《' | '〈' => Opening("\""),
+            '”' | '」' | '》' | '〉' => Closing("\""),
+            '‘' | '『' => Opening("'"),
+            '’' | '』' => Closing("'"),
+            '（' | '(' => Opening("("),
+            '）' | ')' => Closing(")"),
+            '【' | '〔' | '[' => Opening("["),
+            '】' | '〕' | ']' => Closing("]"),
+            _ => return None,
+        };
+        Some(punctuation)
+    }
+}
+
+/// 按照 GB/T 16159《汉语拼音正词法基本规则》拼写文本
+///
+/// 由调用方按词提供文本：同一个词的各音节连写，词与词之间分写，
+/// 以 `a`、`o`、`e` 开头的非首音节前加隔音符号，如 `Xī'ān`。
+/// 句子开头的词和专有名词首字母大写，中文标点转换为相应的西文标点。
+///
+/// *仅在启用 `plain` 特性时可用*
+/// ```
+/// # #[cfg(feature = "with_tone")] {
+/// use pinyin::{Orthography, Pinyin};
+/// let mut orthography = Orthography::new(Pinyin::with_tone);
+/// orthography.push_name("王小明");
+/// orthography.push_word("去");
+/// orthography.push_proper_noun("西安");
+/// orthography.push_word("了。");
+/// orthography.push_word("你好！");
+/// assert_eq!(
+///     orthography.finish(),
+///     "Wáng Xiǎomíng qù Xī'ān le. Nǐhǎo!"
+/// );
+/// # }
+/// ```
+pub struct Orthography<F> {
+    f: F,
+    output: String,
+    /// 当前词在 `output` 中的起始位置，不在词中时为 `None`
+    word_start: Option<usize>,
+    /// 当前词是否为专有名词
+    proper_noun: bool,
+    /// 下一个词是否位于句子开头
+    sentence_start: bool,
+    /// 下一个词之前是否需要空格
+    space: bool,
+    /// 上一个字符为省略号或破折号时跳过紧随的同一符号，如 `……`
+    last_punctuation: Option<char>,
+}
+
+impl<F> Orthography<F>
+where
+    F: Fn(Pinyin) -> &'static str,
+{
+    /// 使用给定的拼音风格创建一个空的拼写器，如 [`Pinyin::with_tone`]
+    pub fn new(f: F) -> Orthography<F> {
+        Orthography {
+            f,
+            output: String::new(),
+            word_start: None,
+            proper_noun: false,
+            sentence_start: true,
+            space: false,
+            last_punctuation: None,
+        }
+    }
+
+    /// 追加一个普通词语
+    ///
+    /// 词中的标点符号和空白字符会结束当前词。
+    /// 启用 `place_name` 特性时，与地名词表完全匹配的词按专有名词处理，并使用地名读音。
+    pub fn push_word(&mut self, word: &str) -> &mut Orthography<F> {
+        #[cfg(feature = "place_name")]
+        {
+            if matches!(match_place_name(word), Some((name, _)) if name == word) {
+                self.push_text(word, to_pinyin_place_name(word), true);
+                return self;
+            }
+        }
+        self.push_text(word, word.to_pinyin(), false);
+        self
+    }
+
+    /// 追加一个专有名词，如地名、机构名，首字母大写
+    pub fn push_proper_noun(&mut self, word: &str) -> &mut Orthography<F> {
+        self.push_text(word, word.to_pinyin(), true);
+        self
+    }
+
+    /// 追加一个人名，姓和名分写，首字母分别大写
+    ///
+    /// 姓使用作为姓氏时的读音，如 单 读作 shàn。
+    pub fn push_name(&mut self, name: &str) -> &mut Orthography<F> {
+        let name = Name::parse(name);
+        self.push_text(name.surname(), name.surname_pinyin(), true);
+        self.push_text(name.given_name(), name.given_name_pinyin(), true);
+        self
+    }
+
+    /// 返回拼写结果
+    pub fn finish(mut self) -> String {
+        self.end_word();
+        self.output
+    }
+
+    fn push_text(
+        &mut self,
+        text: &str,
+        pinyin_list: impl Iterator<Item = Option<Pinyin>>,
+        proper_noun: bool,
+    ) {
+        self.end_word();
+        self.proper_noun = proper_noun;
+        for (ch, pinyin) in text.chars().zip(pinyin_list) {
+            if let Some(pinyin) = pinyin {
+                let syllable = (self.f)(pinyin);
+                if self.start_word() && matches!(pinyin.first_letter(), "a" | "o" | "e") {
+                    self.output.push('\'');
+                }
+                self.output.push_str(syllable);
+                self.last_punctuation = None;
+            } else if let Some(punctuation) = Punctuation::from_char(ch) {
+                self.push_punctuation(ch, punctuation);
+            } else if ch.is_whitespace() {
+                self.end_word();
+            } else {
+                self.start_word();
+                self.output.push(ch);
+                self.last_punctuation = None;
+            }
+        }
+        self.end_word();
+    }
+
+    /// 开始一个新词，已经在词中时返回 `true`
+    fn start_word(&mut self) -> bool {
+        if self.word_start.is_some() {
+            return true;
+        }
+        if self.space {
+            self.output.push(' ');
+        }
+        self.word_start = Some(self.output.len());
+        false
+    }
+
+    fn end_word(&mut self) {
+        let start = match self.word_start.take() {
+            Some(start) => start,
+            None => return,
+        };
+        if self.sentence_start || self.proper_noun {
+            let first = self.output[start..].chars().next().unwrap();
+            let upper = first.to_uppercase().collect::<String>();
+            self.output
+                .replace_range(start..start + first.len_utf8(), &upper);
+        }
+        self.sentence_start = false;
+        self.space = true;
+    }
+
+    fn push_punctuation(&mut self, ch: char, punctuation: Punctuation) {
+        self.end_word();
+        if matches!(ch, '…' | '—') && self.last_punctuation == Some(ch) {
+            return;
+        }
+        self.last_punctuation = Some(ch);
+        match punctuation {
+            Punctuation::SentenceEnd(text) => {
+                self.output.push_str(text);
+                self.sentence_start = true;
+                self.space = true;
+            }
+            Punctuation::Pause(text) | Punctuation::Closing(text) => {
+                self.output.push_str(text);
+                self.space = true;
+            }
+            Punctuation::Ellipsis => {
+                self.output.push_str("...");
+                self.space = true;
+            }
+            Punctuation::Opening(text) => {
+                if self.space {
+                    self.output.push(' ');
+                }
+                self.output.push_str(text);
+                self.space = false;
+            }
+        }
+    }
+}
+
+/// 按照 GB/T 16159《汉语拼音正词法基本规则》拼写以空白字符分词的文本
+///
+/// 参见 [`Orthography`]。
+///
+/// *仅在启用 `plain` 特性时可用*
+/// ```
+/// # use pinyin::*;
+/// assert_eq!(
+///     to_orthography("我们 是 中国人。", Pinyin::plain),
+///     "Women shi zhongguoren."
+/// );
+/// # #[cfg(feature = "with_tone")]
+/// assert_eq!(
+///     to_orthography("“西安 很 好，”他 说。", Pinyin::with_tone),
+///     "\"Xī'ān hěn hǎo,\" tā shuō."
+/// );
+/// ```
+pub fn to_orthography<F>(input: &str, f: F) -> String
+where
+    F: Fn(Pinyin) -> &'static str,
+{
+    let mut orthography = Orthography::new(f);
+    for word in input.split_whitespace() {
+        orthography.push_word(word);
+    }
+    orthography.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_orthography, Orthography};
+    use crate::Pinyin;
+
+    #[test]
+    fn apostrophe() {
+        assert_eq!(to_orthography("西安", Pinyin::plain), "Xi'an");
+        assert_eq!(
+            to_orthography("我 爱 天安门", Pinyin::plain),
+            "Wo ai tian'anmen"
+        );
+        assert_eq!(to_orthography("好 啊", Pinyin::plain), "Hao a");
+    }
+
+    #[test]
+    fn sentence_start() {
+        assert_eq!(
+            to_orthography("你好！我 是 小明？好。", Pinyin::plain),
+            "Nihao! Wo shi xiaoming? Hao."
+        );
+    }
+
+    #[test]
+    fn punctuation() {
+        assert_eq!(
+            to_orthography("看（书）、写 字；好：", Pinyin::plain),
+            "Kan (shu), xie zi; hao:"
+        );
+        assert_eq!(to_orthography("好……好", Pinyin::plain), "Hao... hao");
+        assert_eq!(
+            to_orthography("好…… 好。好", Pinyin::plain),
+            "Hao... hao. Hao"
+        );
+        assert_eq!(to_orthography("《中国》", Pinyin::plain), "\"Zhongguo\"");
+        assert_eq!(to_orthography("好2个 abc", Pinyin::plain), "Hao2ge abc");
+    }
+
+    #[test]
+    fn proper_noun() {
+        let mut orthography = Orthography::new(Pinyin::plain);
+        orthography
+            .push_word("我")
+            .push_word("在")
+            .push_proper_noun("中国")
+            .push_name("欧阳修")
+            .push_name("单田芳");
+        assert_eq!(
+            orthography.finish(),
+            "Wo zai Zhongguo Ouyang Xiu Shan Tianfang"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "place_name")]
+    fn place_name() {
+        assert_eq!(to_orthography("去 六安", Pinyin::plain), "Qu Lu'an");
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(to_orthography("", Pinyin::plain), "");
+        assert_eq!(Orthography::new(Pinyin::plain).finish(), "");
+    }
+}
@@ -0,0 +1,101 @@
+use crate::data::{PARTICLE_A_TABLE, PINYIN_DATA};
+use crate::{Pinyin, Segment};
+
+/// 根据前一个音节的韵母改写语气词 啊 的读音
+///
+/// 前一音节以 a、o、e、ê、i、ü 结尾时读 ya，以 u、ao 结尾时读 wa，以 n 结尾时读 na，
+/// 以 ng 结尾时读 nga，前一音节为 zhi、chi、shi、ri、er 或儿化音节时读 ra。
+/// zi、ci、si 之后的 啊 没有对应的拼音写法，保持不变。
+pub(crate) fn apply_particle_a(segments: &mut [Segment<'_>]) {
+    for i in 1..segments.len() {
+        if segments[i].text != "啊" {
+            continue;
+        }
+        let variant = match segments[i - 1].pinyin.and_then(particle_a_variant) {
+            Some(variant) => variant,
+            None => continue,
+        };
+        let idx = usize::from(PARTICLE_A_TABLE[variant]);
+        segments[i].pinyin = Some(Pinyin(&PINYIN_DATA[idx]));
+    }
+}
+
+/// 返回 啊 在给定音节之后的音变在 `PARTICLE_A_TABLE` 中的序号
+fn particle_a_variant(prev: Pinyin) -> Option<usize> {
+    const YA: usize = 0;
+    const WA: usize = 1;
+    const NA: usize = 2;
+    const NGA: usize = 3;
+    const RA: usize = 4;
+    let initials = prev.initials();
+    let finals = prev.finals_plain();
+    let variant = if finals.ends_with('r') {
+        RA
+    } else if finals == "i" {
+        match initials {
+            "zh" | "ch" | "sh" | "r" => RA,
+            "z" | "c" | "s" => return None,
+            _ => YA,
+        }
+    } else if finals.ends_with("ng") {
+        NGA
+    } else if finals.ends_with('n') {
+        NA
+    } else if finals.ends_with('u') || finals.ends_with("ao") {
+        // j、q、x 以及 y 之后的 u 实际上是 ü
+        match (initials, prev.plain()) {
+            ("j" | "q" | "x", _) => YA,
+            (_, plain) if plain.starts_with("yu") => YA,
+            _ => WA,
+        }
+    } else if finals.ends_with(['a', 'o', 'e', 'i', 'ü', 'ê']) {
+        YA
+    } else {
+        return None;
+    };
+    Some(variant)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Converter, Pinyin};
+
+    fn convert(input: &str) -> Vec<&'static str> {
+        Converter::new()
+            .particle_a(true)
+            .convert(input)
+            .into_iter()
+            .filter_map(|segment| segment.pinyin.map(Pinyin::plain))
+            .collect()
+    }
+
+    #[test]
+    fn assimilation() {
+        assert_eq!(convert("好啊"), vec!["hao", "wa"]);
+        assert_eq!(convert("去啊"), vec!["qu", "ya"]);
+        assert_eq!(convert("快来啊"), vec!["kuai", "lai", "ya"]);
+        assert_eq!(convert("天啊"), vec!["tian", "na"]);
+        assert_eq!(convert("唱啊"), vec!["chang", "nga"]);
+        assert_eq!(convert("是啊"), vec!["shi", "ra"]);
+        assert_eq!(convert("写字啊"), vec!["xie", "zi", "a"]);
+    }
+
+    #[test]
+    fn not_after_syllable() {
+        assert_eq!(convert("啊"), vec!["a"]);
+        assert_eq!(convert("好，啊"), vec!["hao", "a"]);
+    }
+
+    #[test]
+    #[cfg(feature = "erhua")]
+    fn after_erhua() {
+        let result = Converter::new()
+            .erhua(true)
+            .particle_a(true)
+            .convert("玩儿啊")
+            .into_iter()
+            .filter_map(|segment| segment.pinyin.map(Pinyin::plain))
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec!["wanr", "ra"]);
+    }
+}
@@ -0,0 +1,295 @@
+use crate::{Converter, Pinyin};
+
+/// 零声母音节的音素写法
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZeroInitialPhoneme {
+    /// 不输出声母，韵母还原为完整形式，如 `yang` 为 `iang`、`wei` 为 `ui`
+    Restored,
+    /// 以 `y`、`w` 作为声母，韵母按拼写输出，如 `yang` 为 `y ang`
+    Spelled,
+    /// 以给定的音素作为声母，韵母还原为完整形式，如 `yang` 为 `^ iang`、`an` 为 `^ an`
+    Placeholder(&'static str),
+}
+
+/// 儿化音节的音素写法
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErhuaPhoneme {
+    /// 儿化韵作为一个音素，如 `huār` 为 `h uar1`
+    Merged,
+    /// 在韵母之后输出给定的儿化音素，如 `huār` 为 `h ua1 rr`
+    Separate(&'static str),
+}
+
+/// 语音合成前端的音素序列生成器
+///
+/// 每个音节输出声母和带声调数字的韵母两个音素，轻声的声调为 `5`，如 中国人 为 `zh ong1 g uo2 r en2`。
+/// 标点符号转换为停顿音素，连续的标点只输出一个停顿，其它字符被忽略。
+///
+/// `j`、`q`、`x` 后的 `u` 还原为 `ü`，`ü` 默认写作 `v`，如 去 为 `q v4`。
+/// 儿化需要通过 [`converter`] 使用开启了儿化合并的转换器。
+///
+/// *仅在启用 `phoneme` 特性时可用*
+///
+/// [`converter`]: PhonemeTokenizer::converter
+/// ```
+/// use pinyin::PhonemeTokenizer;
+/// let tokenizer = PhonemeTokenizer::new();
+/// assert_eq!(
+///     tokenizer.tokenize("你好，世界。").join(" "),
+///     "n i3 h ao3 sp sh i4 j ie4 sp"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct PhonemeTokenizer {
+    converter: Converter,
+    zero_initial: ZeroInitialPhoneme,
+    umlaut: &'static str,
+    erhua: ErhuaPhoneme,
+    pause: &'static str,
+    prosody_markers: bool,
+}
+
+impl PhonemeTokenizer {
+    /// 返回默认的生成器：零声母不输出声母，`ü` 写作 `v`，儿化音素为 `rr`，停顿音素为 `sp`
+    pub fn new() -> PhonemeTokenizer {
+        PhonemeTokenizer {
+            converter: Converter::new(),
+            zero_initial: ZeroInitialPhoneme::Restored,
+            umlaut: "v",
+            erhua: ErhuaPhoneme::Separate("rr"),
+            pause: "sp",
+            prosody_markers: false,
+        }
+    }
+
+    /// 设置用于取得拼音的转换器
+    /// ```
+    /// # #[cfg(feature = "erhua")] {
+    /// use pinyin::{Converter, ErhuaPhoneme, PhonemeTokenizer};
+    /// let tokenizer = PhonemeTokenizer::new().converter(Converter::new().erhua(true));
+    /// assert_eq!(tokenizer.tokenize("花儿").join(" "), "h ua1 rr");
+    /// let tokenizer = tokenizer.erhua(ErhuaPhoneme::Merged);
+    /// assert_eq!(tokenizer.tokenize("花儿").join(" "), "h uar1");
+    /// # }
+    /// ```
+    pub fn converter(mut self, converter: Converter) -> PhonemeTokenizer {
+        self.converter = converter;
+        self
+    }
+
+    /// 设置零声母音节的写法
+    /// ```
+    /// use pinyin::{PhonemeTokenizer, ZeroInitialPhoneme};
+    /// let tokenizer = PhonemeTokenizer::new();
+    /// assert_eq!(tokenizer.tokenize("要安").join(" "), "iao4 an1");
+    /// let tokenizer = tokenizer.zero_initial(ZeroInitialPhoneme::Spelled);
+    /// assert_eq!(tokenizer.tokenize("要安").join(" "), "y ao4 an1");
+    /// let tokenizer = tokenizer.zero_initial(ZeroInitialPhoneme::Placeholder("^"));
+    /// assert_eq!(tokenizer.tokenize("要安").join(" "), "^ iao4 ^ an1");
+    /// ```
+    pub fn zero_initial(mut self, zero_initial: ZeroInitialPhoneme) -> PhonemeTokenizer {
+        self.zero_initial = zero_initial;
+        self
+    }
+
+    /// 设置 `ü` 的写法，如 `"ü"` 或 `"v"`
+    pub fn umlaut(mut self, umlaut: &'static str) -> PhonemeTokenizer {
+        self.umlaut = umlaut;
+        self
+    }
+
+    /// 设置儿化音节的写法
+    pub fn erhua(mut self, erhua: ErhuaPhoneme) -> PhonemeTokenizer {
+        self.erhua = erhua;
+        self
+    }
+
+    /// 设置停顿音素
+    pub fn pause(mut self, pause: &'static str) -> PhonemeTokenizer {
+        self.pause = pause;
+        self
+    }
+
+    /// 设置是否保留文本中 `#1` 到 `#4` 形式的韵律标记
+    ///
+    /// 开启后韵律标记作为音素原样输出，否则其中的 `#` 作为标点转换为停顿。
+    /// ```
+    /// use pinyin::PhonemeTokenizer;
+    /// let tokenizer = PhonemeTokenizer::new().prosody_markers(true);
+    /// assert_eq!(
+    ///     tokenizer.tokenize("你好#1世界#4").join(" "),
+    ///     "n i3 h ao3 #1 sh i4 j ie4 #4"
+    /// );
+    /// ```
+    pub fn prosody_markers(mut self, enable: bool) -> PhonemeTokenizer {
+        self.prosody_markers = enable;
+        self
+    }
+
+    /// 生成音素序列
+    pub fn tokenize(&self, input: &str) -> Vec<String> {
+        let mut tokens = vec![];
+        let mut text = String::new();
+        for segment in self.converter.convert(input) {
+            match segment.pinyin {
+                Some(pinyin) => {
+                    self.push_text(&mut tokens, &text);
+                    text.clear();
+                    self.push_syllable(&mut tokens, pinyin);
+                }
+                None => text.push_str(segment.text),
+            }
+        }
+        self.push_text(&mut tokens, &text);
+        tokens
+    }
+
+    fn push_syllable(&self, tokens: &mut Vec<String>, pinyin: Pinyin) {
+        let syllable = pinyin.plain();
+        let (mut initial, mut final_) = match syllable {
+            // 不含元音的音节整体作为韵母
+            "m" | "n" | "ng" => ("", syllable),
+            _ => (pinyin.initials(), pinyin.finals_plain()),
+        };
+        let erhua = syllable != "er" && final_.ends_with('r');
+        if erhua {
+            final_ = &final_[..final_.len() - 1];
+        }
+        let mut final_ = final_.to_string();
+        if initial.is_empty() {
+            let spelled = self.zero_initial == ZeroInitialPhoneme::Spelled;
+            if spelled && final_.starts_with(['y', 'w']) {
+                initial = &syllable[..1];
+                final_.remove(0);
+            } else if !spelled {
+                final_ = restore_final(&final_);
+            }
+            if let ZeroInitialPhoneme::Placeholder(placeholder) = self.zero_initial {
+                initial = placeholder;
+            }
+        }
+        if matches!(initial, "j" | "q" | "x" | "y") && final_.starts_with('u') {
+            final_.replace_range(..1, "ü");
+        }
+        let final_ = final_.replace('ü', self.umlaut);
+        if !initial.is_empty() {
+            tokens.push(initial.to_string());
+        }
+        let tone = match pinyin.tone() {
+            0 => 5,
+            tone => tone,
+        };
+        match self.erhua {
+            ErhuaPhoneme::Merged if erhua => tokens.push(format!("{final_}r{tone}")),
+            ErhuaPhoneme::Separate(token) if erhua => {
+                tokens.push(format!("{final_}{tone}"));
+                tokens.push(token.to_string());
+            }
+            _ => tokens.push(format!("{final_}{tone}")),
+        }
+    }
+
+    fn push_text(&self, tokens: &mut Vec<String>, text: &str) {
+        let mut chars = text.chars().peekable();
+        while let Some(ch) = chars.next() {
+            if self.prosody_markers && ch == '#' {
+                if let Some(level) = chars.next_if(|level| ('1'..='4').contains(level)) {
+                    tokens.push(format!("#{level}"));
+                    continue;
+                }
+            }
+            let is_punctuation = !ch.is_alphanumeric() && !ch.is_whitespace();
+            if is_punctuation && tokens.last().map(String::as_str) != Some(self.pause) {
+                tokens.push(self.pause.to_string());
+            }
+        }
+    }
+}
+
+impl Default for PhonemeTokenizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 将零声母音节还原为完整的韵母，写法与有声母时相同，如 `you` 为 `iu`
+fn restore_final(syllable: &str) -> String {
+    let final_ = match syllable {
+        "yi" | "yin" | "ying" | "wu" => &syllable[1..],
+        "you" => "iu",
+        "wei" => "ui",
+        "wen" => "un",
+        "yu" | "yue" | "yuan" | "yun" => return format!("ü{}", &syllable[2..]),
+        _ => match syllable.strip_prefix('y') {
+            Some(rest) => return format!("i{rest}"),
+            None => match syllable.strip_prefix('w') {
+                Some(rest) => return format!("u{rest}"),
+                None => syllable,
+            },
+        },
+    };
+    final_.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::restore_final;
+    use crate::{PhonemeTokenizer, ZeroInitialPhoneme};
+
+    fn tokenize(tokenizer: &PhonemeTokenizer, input: &str) -> String {
+        tokenizer.tokenize(input).join(" ")
+    }
+
+    #[test]
+    fn zero_initial() {
+        let cases = [
+            ("yi", "i"),
+            ("ya", "ia"),
+            ("you", "iu"),
+            ("yong", "iong"),
+            ("yue", "üe"),
+            ("yun", "ün"),
+            ("wu", "u"),
+            ("wo", "uo"),
+            ("wei", "ui"),
+            ("wen", "un"),
+            ("weng", "ueng"),
+            ("ang", "ang"),
+        ];
+        for (syllable, expected) in cases.iter() {
+            assert_eq!(restore_final(syllable), *expected);
+        }
+        let tokenizer = PhonemeTokenizer::new();
+        assert_eq!(tokenize(&tokenizer, "一外有月二"), "i1 uai4 iu3 ve4 er4");
+        let tokenizer = tokenizer.zero_initial(ZeroInitialPhoneme::Spelled);
+        assert_eq!(
+            tokenize(&tokenizer, "一外有月二"),
+            "y i1 w ai4 y ou3 y ve4 er4"
+        );
+    }
+
+    #[test]
+    fn umlaut() {
+        let tokenizer = PhonemeTokenizer::new();
+        assert_eq!(tokenize(&tokenizer, "女学去绿"), "n v3 x ve2 q v4 l v4");
+        let tokenizer = tokenizer.umlaut("ü");
+        assert_eq!(tokenize(&tokenizer, "女学去绿"), "n ü3 x üe2 q ü4 l ü4");
+    }
+
+    #[test]
+    fn tones() {
+        let tokenizer = PhonemeTokenizer::new();
+        assert_eq!(tokenize(&tokenizer, "中国人"), "zh ong1 g uo2 r en2");
+        assert_eq!(tokenize(&tokenizer, "我的"), "uo3 d e5");
+    }
+
+    #[test]
+    fn pauses() {
+        let tokenizer = PhonemeTokenizer::new();
+        assert_eq!(tokenize(&tokenizer, "好！！ 好 a1"), "h ao3 sp h ao3");
+        assert_eq!(tokenize(&tokenizer, "好#1好"), "h ao3 sp h ao3");
+        let tokenizer = tokenizer.pause("SIL");
+        assert_eq!(tokenize(&tokenizer, "好，好。"), "h ao3 SIL h ao3 SIL");
+        assert!(tokenizer.tokenize("").is_empty());
+    }
+}
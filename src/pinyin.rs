@@ -1,3 +1,4 @@
+use crate::contour::ToneContour;
 use crate::data::PINYIN_DATA;
 use crate::{get_block_and_index, PinyinData};
 use std::str::Chars;
@@ -70,12 +71,213 @@ impl Pinyin {
         &self.0.plain[..ch.len_utf8()]
     }
 
-    #[cfg(feature = "compat")]
+    /// 耶鲁拼音风格，声调以符号表示
+    ///
+    /// *仅在启用 `yale` 特性时可用*
+    /// ```
+    /// # use pinyin::*;
+    /// assert_eq!(to_pinyin_vec("中川小", Pinyin::yale), vec!["jūng", "chwān", "syǎu"]);
+    /// assert_eq!(to_pinyin_vec("字是", Pinyin::yale), vec!["dz\u{300}", "shr\u{300}"]);
+    /// ```
+    #[cfg(feature = "yale")]
+    pub fn yale(self) -> &'static str {
+        self.0.yale
+    }
+
+    /// 耶鲁拼音风格，声调以数字 1-4 表示，轻声不标注
+    ///
+    /// *仅在启用 `yale` 特性时可用*
+    /// ```
+    /// # use pinyin::*;
+    /// assert_eq!(to_pinyin_vec("中川小", Pinyin::yale_num), vec!["jung1", "chwan1", "syau3"]);
+    /// ```
+    #[cfg(feature = "yale")]
+    pub fn yale_num(self) -> &'static str {
+        self.0.yale_num
+    }
+
+    /// 注音符号第二式风格，声调以符号表示
+    ///
+    /// *仅在启用 `mps2` 特性时可用*
+    /// ```
+    /// # use pinyin::*;
+    /// assert_eq!(to_pinyin_vec("中心是", Pinyin::mps2), vec!["jūng", "shīn", "shr\u{300}"]);
+    /// ```
+    #[cfg(feature = "mps2")]
+    pub fn mps2(self) -> &'static str {
+        self.0.mps2
+    }
+
+    /// 注音符号第二式风格，不带声调
+    ///
+    /// *仅在启用 `mps2` 特性时可用*
+    /// ```
+    /// # use pinyin::*;
+    /// assert_eq!(to_pinyin_vec("中心是", Pinyin::mps2_plain), vec!["jung", "shin", "shr"]);
+    /// ```
+    #[cfg(feature = "mps2")]
+    pub fn mps2_plain(self) -> &'static str {
+        self.0.mps2_plain
+    }
+
+    /// 通用拼音风格，第一声不标注，轻声以 `˚` 表示
+    ///
+    /// *仅在启用 `tongyong` 特性时可用*
+    /// ```
+    /// # use pinyin::*;
+    /// assert_eq!(to_pinyin_vec("中心是", Pinyin::tongyong), vec!["jhong", "sin", "shìh"]);
+    /// assert_eq!(to_pinyin_vec("的", Pinyin::tongyong), vec!["de\u{30A}"]);
+    /// ```
+    #[cfg(feature = "tongyong")]
+    pub fn tongyong(self) -> &'static str {
+        self.0.tongyong
+    }
+
+    /// 通用拼音风格，不带声调
+    ///
+    /// *仅在启用 `tongyong` 特性时可用*
+    /// ```
+    /// # use pinyin::*;
+    /// assert_eq!(to_pinyin_vec("中心是", Pinyin::tongyong_plain), vec!["jhong", "sin", "shih"]);
+    /// ```
+    #[cfg(feature = "tongyong")]
+    pub fn tongyong_plain(self) -> &'static str {
+        self.0.tongyong_plain
+    }
+
+    /// 国语罗马字风格，以拼写变化表示声调，轻声音节以 `.` 开头
+    ///
+    /// *仅在启用 `gwoyeu_romatzyh` 特性时可用*
+    /// ```
+    /// # use pinyin::*;
+    /// assert_eq!(
+    ///     to_pinyin_vec("锅国果过", Pinyin::gwoyeu_romatzyh),
+    ///     vec!["guo", "gwo", "guoo", "guoh"]
+    /// );
+    /// assert_eq!(to_pinyin_vec("妈的", Pinyin::gwoyeu_romatzyh), vec!["mha", ".de"]);
+    /// ```
+    #[cfg(feature = "gwoyeu_romatzyh")]
+    pub fn gwoyeu_romatzyh(self) -> &'static str {
+        self.0.gwoyeu_romatzyh
+    }
+
+    /// 西里尔字母风格（巴拉第系统），连写多个音节时参见 [`to_cyrillic`](crate::to_cyrillic)
+    ///
+    /// *仅在启用 `cyrillic` 特性时可用*
+    /// ```
+    /// # use pinyin::*;
+    /// assert_eq!(to_pinyin_vec("北京", Pinyin::cyrillic), vec!["бэй", "цзин"]);
+    /// ```
+    #[cfg(feature = "cyrillic")]
+    pub fn cyrillic(self) -> &'static str {
+        self.0.cyrillic
+    }
+
+    /// 国际音标风格，声调以五度标记字母表示，轻声不标注
+    ///
+    /// *仅在启用 `ipa` 特性时可用*
+    /// ```
+    /// # use pinyin::*;
+    /// assert_eq!(to_pinyin_vec("中国人", Pinyin::ipa), vec!["ʈʂʊŋ˥", "kwo˧˥", "ʐən˧˥"]);
+    /// ```
+    #[cfg(feature = "ipa")]
+    pub fn ipa(self) -> &'static str {
+        self.0.ipa
+    }
+
+    /// 国际音标风格，声调以上标数字表示，轻声不标注
+    ///
+    /// *仅在启用 `ipa` 特性时可用*
+    /// ```
+    /// # use pinyin::*;
+    /// assert_eq!(to_pinyin_vec("中国人", Pinyin::ipa_num), vec!["ʈʂʊŋ⁵⁵", "kwo³⁵", "ʐən³⁵"]);
+    /// ```
+    #[cfg(feature = "ipa")]
+    pub fn ipa_num(self) -> &'static str {
+        self.0.ipa_num
+    }
+
+    /// 韩文风格，依照韩国外来语标记法中的汉语拼音与韩文对照表，不标注声调
+    ///
+    /// *仅在启用 `hangul` 特性时可用*
+    /// ```
+    /// # use pinyin::*;
+    /// assert_eq!(to_pinyin_vec("北京", Pinyin::hangul), vec!["베이", "징"]);
+    /// ```
+    #[cfg(feature = "hangul")]
+    pub fn hangul(self) -> &'static str {
+        self.0.hangul
+    }
+
+    /// 片假名风格，不标注声调
+    ///
+    /// 不送气声母用浊音表示，送气声母用清音表示，单元音韵母加长音符号 `ー`。
+    /// 北京 的惯用读法 `ペキン` 等不在此列。
+    ///
+    /// *仅在启用 `katakana` 特性时可用*
+    /// ```
+    /// # use pinyin::*;
+    /// assert_eq!(to_pinyin_vec("北京", Pinyin::katakana), vec!["ベイ", "ジン"]);
+    /// assert_eq!(to_pinyin_vec("李", Pinyin::katakana), vec!["リー"]);
+    /// ```
+    #[cfg(feature = "katakana")]
+    pub fn katakana(self) -> &'static str {
+        self.0.katakana
+    }
+
+    /// 儿化形式，如 `huā` 的儿化形式为 `huār`
+    #[cfg(feature = "erhua")]
+    pub(crate) fn erhua(self) -> Option<Pinyin> {
+        match usize::from(self.0.erhua) {
+            0 => None,
+            idx => Some(Pinyin(&PINYIN_DATA[idx])),
+        }
+    }
+
+    /// 轻声形式，如 `mā` 的轻声形式为 `ma`
+    #[cfg(feature = "neutral_tone")]
+    pub(crate) fn neutral(self) -> Pinyin {
+        Pinyin(&PINYIN_DATA[usize::from(self.0.neutral)])
+    }
+
+    /// 声调，`1` 到 `4` 表示四声，`0` 表示轻声
+    /// ```
+    /// use pinyin::ToPinyin;
+    /// assert_eq!('拼'.to_pinyin().unwrap().tone(), 1);
+    /// assert_eq!('字'.to_pinyin().unwrap().tone(), 4);
+    /// assert_eq!('的'.to_pinyin().unwrap().tone(), 0);
+    /// ```
+    pub fn tone(self) -> u8 {
+        self.0.tone
+    }
+
+    /// 本调的调值，连读时的实际调值参见 [`tone_contours`](crate::tone_contours)
+    /// ```
+    /// use pinyin::ToPinyin;
+    /// let contour = '马'.to_pinyin().unwrap().tone_contour();
+    /// assert_eq!(contour.pitches(), &[2, 1, 4]);
+    /// assert_eq!(contour.chao_letters(), "˨˩˦");
+    /// ```
+    pub fn tone_contour(self) -> ToneContour {
+        ToneContour::from_tone(self.0.tone)
+    }
+
+    #[cfg(any(
+        feature = "compat",
+        feature = "particle_a",
+        feature = "braille",
+        feature = "phoneme"
+    ))]
     pub(crate) fn initials(self) -> &'static str {
         &self.0.plain[..self.0.split]
     }
 
-    #[cfg(feature = "compat")]
+    #[cfg(any(
+        feature = "compat",
+        feature = "particle_a",
+        feature = "braille",
+        feature = "phoneme"
+    ))]
     pub(crate) fn finals_plain(self) -> &'static str {
         &self.0.plain[self.0.split..]
     }
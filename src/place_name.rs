@@ -0,0 +1,160 @@
+use crate::data::PLACE_NAME_TABLE;
+use crate::{match_phrase, push_syllable, PhrasePinyinIter, Pinyin, ToPinyin};
+use std::str::Chars;
+
+/// 地名中与专名分写的通名
+const GENERIC_NAMES: &[char] = &['省', '市', '县', '区', '镇', '乡', '村', '路', '街'];
+
+/// 匹配输入开头的地名，返回该地名及其各字的读音
+pub(crate) fn match_place_name(input: &str) -> Option<(&'static str, PhrasePinyinIter)> {
+    match_phrase(PLACE_NAME_TABLE, input)
+}
+
+/// 获取地名的拼音，地名中的字使用地名读音，如 六安 读作 lù ān
+///
+/// 输入应当是已知的地名，如地址中的各级行政区名称。普通文本请使用 [`ToPinyin`]，
+/// 以免 家长春节 中的 长春 被当作地名。
+///
+/// *仅在启用 `place_name` 特性时可用*
+/// ```
+/// # use pinyin::*;
+/// let pinyin = to_pinyin_place_name("六安市")
+///     .map(|pinyin| pinyin.unwrap().plain())
+///     .collect::<Vec<_>>();
+/// assert_eq!(pinyin, vec!["lu", "an", "shi"]);
+/// ```
+pub fn to_pinyin_place_name(name: &str) -> PlaceNamePinyinIter<'_> {
+    PlaceNamePinyinIter {
+        chars: name.chars(),
+        phrase: None,
+    }
+}
+
+/// *辅助迭代器*，用于获取地名的拼音信息
+pub struct PlaceNamePinyinIter<'a> {
+    chars: Chars<'a>,
+    /// 当前正在输出的地名中剩余各字的拼音
+    phrase: Option<PhrasePinyinIter>,
+}
+
+impl<'a> Iterator for PlaceNamePinyinIter<'a> {
+    type Item = Option<Pinyin>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut pinyin = self.phrase.as_mut().and_then(Iterator::next);
+        if pinyin.is_none() {
+            self.phrase = match_place_name(self.chars.as_str()).map(|(_, pinyin)| pinyin);
+            pinyin = self.phrase.as_mut().and_then(Iterator::next);
+        }
+        match pinyin {
+            Some(pinyin) => {
+                self.chars.next();
+                Some(Some(pinyin))
+            }
+            None => self.chars.next().map(|c| c.to_pinyin()),
+        }
+    }
+}
+
+/// 按照地名拼写规则将地名转换为拼音
+///
+/// 地名中各音节连写，首字母大写，以 `a`、`o`、`e` 开头的非首音节前加隔音符号。
+/// 多音节专名之后的通名（如 省、市、县）分写并首字母大写。
+/// 地名中的字使用地名读音，如 六安 读作 lù ān，蚌埠 读作 bèng bù。
+///
+/// *仅在启用 `place_name` 特性时可用*
+/// ```
+/// # use pinyin::*;
+/// assert_eq!(romanize_place_name("六安", Pinyin::plain), "Lu'an");
+/// assert_eq!(romanize_place_name("蚌埠市", Pinyin::plain), "Bengbu Shi");
+/// assert_eq!(romanize_place_name("单县", Pinyin::plain), "Shanxian");
+/// # #[cfg(feature = "with_tone")]
+/// assert_eq!(romanize_place_name("番禺区", Pinyin::with_tone), "Pānyú Qū");
+/// ```
+pub fn romanize_place_name<F>(name: &str, f: F) -> String
+where
+    F: Fn(Pinyin) -> &'static str,
+{
+    let name = name.trim();
+    let (specific, generic) = match name.char_indices().last() {
+        Some((pos, ch)) if GENERIC_NAMES.contains(&ch) && name[..pos].chars().count() > 1 => {
+            (&name[..pos], &name[pos..])
+        }
+        _ => (name, ""),
+    };
+    let mut result = romanize_word(specific, &f);
+    if !generic.is_empty() {
+        result.push(' ');
+        result.push_str(&romanize_word(generic, &f));
+    }
+    result
+}
+
+fn romanize_word<F>(word: &str, f: &F) -> String
+where
+    F: Fn(Pinyin) -> &'static str,
+{
+    let mut result = String::new();
+    for (ch, pinyin) in word.chars().zip(to_pinyin_place_name(word)) {
+        match pinyin {
+            Some(pinyin) => push_syllable(&mut result, pinyin, f(pinyin)),
+            None => result.push(ch),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_pinyin_place_name;
+    use crate::{to_pinyin_vec, Pinyin};
+
+    fn place_name_vec(name: &str) -> Vec<&'static str> {
+        to_pinyin_place_name(name)
+            .map(|pinyin| pinyin.map_or("", Pinyin::plain))
+            .collect()
+    }
+
+    #[test]
+    fn place_name() {
+        assert_eq!(
+            place_name_vec("六安六安，番禺"),
+            vec!["lu", "an", "lu", "an", "", "pan", "yu"]
+        );
+        assert_eq!(place_name_vec("丽水市"), vec!["li", "shui", "shi"]);
+        assert_eq!(place_name_vec("六"), vec!["liu"]);
+    }
+
+    #[test]
+    #[cfg(feature = "with_tone")]
+    fn converter() {
+        let result = crate::Converter::new()
+            .place_name(true)
+            .convert("我在六安")
+            .into_iter()
+            .filter_map(|segment| segment.pinyin.map(Pinyin::with_tone))
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec!["wǒ", "zài", "lù", "ān"]);
+        let result = crate::Converter::new()
+            .convert("我在六安")
+            .into_iter()
+            .filter_map(|segment| segment.pinyin.map(Pinyin::with_tone))
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec!["wǒ", "zài", "liù", "ān"]);
+    }
+
+    #[test]
+    fn ordinary_text() {
+        // 家长春节、简单县城、天台上、六合彩，测试数据中没有的字已省略
+        assert_eq!(
+            to_pinyin_vec("家长春", Pinyin::plain),
+            vec!["jia", "zhang", "chun"]
+        );
+        assert_eq!(to_pinyin_vec("单县", Pinyin::plain), vec!["dan", "xian"]);
+        assert_eq!(
+            to_pinyin_vec("天台上", Pinyin::plain),
+            vec!["tian", "tai", "shang"]
+        );
+        assert_eq!(to_pinyin_vec("六合", Pinyin::plain), vec!["liu", "he"]);
+    }
+}
@@ -0,0 +1,180 @@
+#[cfg(feature = "heteronym")]
+use crate::ToPinyinMulti;
+use crate::{Pinyin, ToPinyin};
+
+/// HTML 注音（`<ruby>`）渲染器
+///
+/// 每个有拼音的字单独生成一个 `<ruby>` 元素，其它字符原样输出，
+/// 所有文本都会进行 HTML 转义。
+/// ```
+/// # #[cfg(feature = "with_tone")] {
+/// use pinyin::{Pinyin, RubyRenderer};
+/// let renderer = RubyRenderer::new(Pinyin::with_tone);
+/// assert_eq!(
+///     renderer.render("漢字<b>"),
+///     "<ruby>漢<rt>hàn</rt></ruby><ruby>字<rt>zì</rt></ruby>&lt;b&gt;"
+/// );
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RubyRenderer<F> {
+    style: F,
+    rp: bool,
+    #[cfg(feature = "heteronym")]
+    heteronym: bool,
+}
+
+impl<F> RubyRenderer<F>
+where
+    F: Fn(Pinyin) -> &'static str,
+{
+    /// 使用给定的拼音风格创建渲染器，如 [`Pinyin::with_tone`]
+    pub fn new(style: F) -> RubyRenderer<F> {
+        RubyRenderer {
+            style,
+            rp: false,
+            #[cfg(feature = "heteronym")]
+            heteronym: false,
+        }
+    }
+
+    /// 设置是否输出 `<rp>` 元素，使不支持 `<ruby>` 的浏览器将拼音显示在括号中
+    /// ```
+    /// # #[cfg(feature = "plain")] {
+    /// use pinyin::{Pinyin, RubyRenderer};
+    /// let renderer = RubyRenderer::new(Pinyin::plain).rp(true);
+    /// assert_eq!(
+    ///     renderer.render("字"),
+    ///     "<ruby>字<rp>(</rp><rt>zi</rt><rp>)</rp></ruby>"
+    /// );
+    /// # }
+    /// ```
+    pub fn rp(mut self, enable: bool) -> RubyRenderer<F> {
+        self.rp = enable;
+        self
+    }
+
+    /// 设置是否列出多音字的所有读音，各读音之间以 `/` 分隔
+    ///
+    /// 在所用风格下相同的读音只列出一次，如不带声调时 中 只标注为 `zhong`。
+    ///
+    /// *仅在启用 `heteronym` 特性时可用*
+    /// ```
+    /// # #[cfg(feature = "with_tone")] {
+    /// use pinyin::{Pinyin, RubyRenderer};
+    /// let renderer = RubyRenderer::new(Pinyin::with_tone).heteronym(true);
+    /// assert_eq!(renderer.render("还"), "<ruby>还<rt>hái/huán/fú</rt></ruby>");
+    /// # }
+    /// ```
+    #[cfg(feature = "heteronym")]
+    pub fn heteronym(mut self, enable: bool) -> RubyRenderer<F> {
+        self.heteronym = enable;
+        self
+    }
+
+    /// 渲染给定的字符串
+    pub fn render(&self, input: &str) -> String {
+        let mut output = String::with_capacity(input.len() * 4);
+        #[cfg(feature = "heteronym")]
+        if self.heteronym {
+            for (ch, multi) in input.chars().zip(input.to_pinyin_multi()) {
+                match multi {
+                    Some(multi) => {
+                        let mut readings = vec![];
+                        for reading in multi.into_iter().map(&self.style) {
+                            if !readings.contains(&reading) {
+                                readings.push(reading);
+                            }
+                        }
+                        self.push_ruby(&mut output, ch, &readings.join("/"));
+                    }
+                    None => push_escaped_char(&mut output, ch),
+                }
+            }
+            return output;
+        }
+        for (ch, pinyin) in input.chars().zip(input.to_pinyin()) {
+            match pinyin {
+                Some(pinyin) => self.push_ruby(&mut output, ch, (self.style)(pinyin)),
+                None => push_escaped_char(&mut output, ch),
+            }
+        }
+        output
+    }
+
+    fn push_ruby(&self, output: &mut String, ch: char, annotation: &str) {
+        output.push_str("<ruby>");
+        push_escaped_char(output, ch);
+        if self.rp {
+            output.push_str("<rp>(</rp>");
+        }
+        output.push_str("<rt>");
+        push_escaped(output, annotation);
+        output.push_str("</rt>");
+        if self.rp {
+            output.push_str("<rp>)</rp>");
+        }
+        output.push_str("</ruby>");
+    }
+}
+
+/// 将文本进行 HTML 转义后追加到输出中
+pub(crate) fn push_escaped(output: &mut String, text: &str) {
+    text.chars().for_each(|ch| push_escaped_char(output, ch));
+}
+
+fn push_escaped_char(output: &mut String, ch: char) {
+    match ch {
+        '&' => output.push_str("&amp;"),
+        '<' => output.push_str("&lt;"),
+        '>' => output.push_str("&gt;"),
+        '"' => output.push_str("&quot;"),
+        '\'' => output.push_str("&#39;"),
+        _ => output.push(ch),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "plain")]
+mod tests {
+    use crate::{Pinyin, RubyRenderer};
+
+    #[test]
+    fn escape() {
+        let renderer = RubyRenderer::new(Pinyin::plain);
+        assert_eq!(
+            renderer.render("<a href=\"x\">'&'</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn mixed_text() {
+        let renderer = RubyRenderer::new(Pinyin::plain);
+        assert_eq!(
+            renderer.render("我爱 Rust！"),
+            "<ruby>我<rt>wo</rt></ruby><ruby>爱<rt>ai</rt></ruby> Rust！"
+        );
+        assert_eq!(renderer.render(""), "");
+    }
+
+    #[test]
+    fn custom_style() {
+        let renderer = RubyRenderer::new(Pinyin::first_letter).rp(true);
+        assert_eq!(
+            renderer.render("中国"),
+            "<ruby>中<rp>(</rp><rt>z</rt><rp>)</rp></ruby><ruby>国<rp>(</rp><rt>g</rt><rp>)</rp></ruby>"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "heteronym")]
+    fn heteronym() {
+        let renderer = RubyRenderer::new(Pinyin::plain).heteronym(true).rp(true);
+        assert!(renderer.render("还").contains("<rt>hai/huan/fu</rt>"));
+        assert_eq!(
+            renderer.render("中a"),
+            "<ruby>中<rp>(</rp><rt>zhong</rt><rp>)</rp></ruby>a"
+        );
+    }
+}
@@ -0,0 +1,333 @@
+use crate::data::SYLLABLE_TABLE;
+use std::ops::Range;
+
+/// 音节的最大长度，如 `zhuang`
+const MAX_SYLLABLE_LEN: usize = 6;
+
+/// 最多返回的切分方式个数
+const MAX_RESULTS: usize = 32;
+
+/// 带声调的字母及其对应的字母和声调
+const TONE_MARKS: &[(char, char, u8)] = &[
+    ('ā', 'a', 1),
+    ('á', 'a', 2),
+    ('ǎ', 'a', 3),
+    ('à', 'a', 4),
+    ('ē', 'e', 1),
+    ('é', 'e', 2),
+    ('ě', 'e', 3),
+    ('è', 'e', 4),
+    ('ế', 'ê', 2),
+    ('ề', 'ê', 4),
+    ('ō', 'o', 1),
+    ('ó', 'o', 2),
+    ('ǒ', 'o', 3),
+    ('ò', 'o', 4),
+    ('ī', 'i', 1),
+    ('í', 'i', 2),
+    ('ǐ', 'i', 3),
+    ('ì', 'i', 4),
+    ('ū', 'u', 1),
+    ('ú', 'u', 2),
+    ('ǔ', 'u', 3),
+    ('ù', 'u', 4),
+    ('ǖ', 'ü', 1),
+    ('ǘ', 'ü', 2),
+    ('ǚ', 'ü', 3),
+    ('ǜ', 'ü', 4),
+    ('ń', 'n', 2),
+    ('ň', 'n', 3),
+    ('ǹ', 'n', 4),
+    ('ḿ', 'm', 2),
+];
+
+/// 组合用声调符号及其对应的声调
+const COMBINING_TONE_MARKS: &[(char, u8)] = &[
+    ('\u{304}', 1),
+    ('\u{301}', 2),
+    ('\u{30c}', 3),
+    ('\u{300}', 4),
+];
+
+/// 从拼音串中切分出的一个音节
+///
+/// *仅在启用 `plain` 特性时可用*
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyllableSpan<'a> {
+    /// 音节在原字符串中的文本，包括声调符号和末尾的声调数字
+    pub text: &'a str,
+    /// 音节在原字符串中的字节范围
+    pub range: Range<usize>,
+    /// 不带声调的音节，`v` 会转换为 `ü`，如 `lü`
+    pub syllable: &'static str,
+    /// 声调，`1` 到 `4` 表示四声，`5` 或 `0` 表示轻声，没有标注声调时为 `None`
+    pub tone: Option<u8>,
+}
+
+/// 输入中的一个字母
+struct Letter {
+    /// 不带声调的小写字母
+    ch: char,
+    range: Range<usize>,
+    /// 字母上标注的声调
+    tone: Option<u8>,
+    /// 字母后面的声调数字
+    tone_num: Option<u8>,
+    /// 字母后面是否有隔音符号、空格等分隔符或者声调数字
+    boundary: bool,
+}
+
+/// 将没有分隔的拼音串切分为音节，返回最可能的若干种切分方式
+///
+/// 音节表来自拼音数据中出现的所有读音。输入可以不带声调，也可以带声调符号或在音节末尾带声调数字，
+/// 隔音符号 `'`、空格和 `-` 会被视为音节的分界。`v` 视为 `ü`，大小写不敏感。
+///
+/// 结果按照可能性排列：优先不含 `n`、`m`、`hng` 等叹词音节的切分，
+/// 其次是不需要补充隔音符号的切分（即以 `a`、`o`、`e` 开头的非首音节前已有分界），
+/// 最后是音节数较少的切分。排名相同的切分按照首个音节从短到长的顺序排列。
+/// 长串的切分方式会随长度指数增长，因此最多只返回排名最靠前的 32 种。
+/// 输入中包含无法识别的字符或无法切分时返回空列表。
+///
+/// *仅在启用 `plain` 特性时可用*
+/// ```
+/// # use pinyin::*;
+/// let to_vec = |input| {
+///     segment_pinyin(input)
+///         .into_iter()
+///         .map(|spans| spans.into_iter().map(|span| span.syllable).collect::<Vec<_>>())
+///         .collect::<Vec<_>>()
+/// };
+/// assert_eq!(to_vec("xian")[..2], [vec!["xian"], vec!["xi", "an"]]);
+/// assert_eq!(to_vec("xi'an")[0], vec!["xi", "an"]);
+/// assert_eq!(to_vec("xi1an1")[0], vec!["xi", "an"]);
+/// assert_eq!(to_vec("xīān")[0], vec!["xi", "an"]);
+///
+/// let spans = segment_pinyin("zhōngguó").remove(0);
+/// assert_eq!(spans[1].text, "guó");
+/// assert_eq!(spans[1].range, 6..10);
+/// assert_eq!(spans[1].tone, Some(2));
+/// ```
+pub fn segment_pinyin(input: &str) -> Vec<Vec<SyllableSpan<'_>>> {
+    let letters = match parse_letters(input) {
+        Some(letters) => letters,
+        None => return vec![],
+    };
+    if letters.is_empty() {
+        return vec![];
+    }
+    // best[i] 为从第 i 个字母开始的后缀的最优切分，按排名排列，
+    // 每一项记录排名依据、第一个音节以及余下部分在 best[i + 音节长度] 中的序号
+    let mut best: Vec<Vec<(Cost, SyllableSpan<'_>, usize, usize)>> = vec![vec![]; letters.len()];
+    for start in (0..letters.len()).rev() {
+        let mut candidates = vec![];
+        for (end, span) in syllables_at(input, &letters, start) {
+            let cost = Cost {
+                interjections: !span.syllable.contains(['a', 'e', 'i', 'o', 'u', 'ü', 'ê'])
+                    as usize,
+                missing_apostrophes: (start > 0
+                    && !letters[start - 1].boundary
+                    && span.syllable.starts_with(['a', 'o', 'e']))
+                    as usize,
+                syllables: 1,
+            };
+            if end == letters.len() {
+                candidates.push((cost, span, end, 0));
+                continue;
+            }
+            for (rank, (rest, _, _, _)) in best[end].iter().enumerate() {
+                candidates.push((cost.add(rest), span.clone(), end, rank));
+            }
+        }
+        candidates.sort_by_key(|(cost, _, _, _)| *cost);
+        candidates.truncate(MAX_RESULTS);
+        best[start] = candidates;
+    }
+    (0..best[0].len())
+        .map(|rank| {
+            let mut spans = vec![];
+            let (mut start, mut rank) = (0, rank);
+            while start < letters.len() {
+                let (_, span, end, next) = &best[start][rank];
+                spans.push(span.clone());
+                start = *end;
+                rank = *next;
+            }
+            spans
+        })
+        .collect()
+}
+
+/// 切分方式的排名依据，越小越靠前
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Cost {
+    /// 不含元音的叹词音节个数
+    interjections: usize,
+    /// 以 `a`、`o`、`e` 开头却与前一音节直接相连的音节个数
+    missing_apostrophes: usize,
+    /// 音节个数
+    syllables: usize,
+}
+
+impl Cost {
+    fn add(self, other: &Cost) -> Cost {
+        Cost {
+            interjections: self.interjections + other.interjections,
+            missing_apostrophes: self.missing_apostrophes + other.missing_apostrophes,
+            syllables: self.syllables + other.syllables,
+        }
+    }
+}
+
+fn parse_letters(input: &str) -> Option<Vec<Letter>> {
+    let mut letters: Vec<Letter> = vec![];
+    for (pos, ch) in input.char_indices() {
+        let end = pos + ch.len_utf8();
+        let lower = ch.to_lowercase().next().unwrap();
+        if let Some((_, base, tone)) = TONE_MARKS.iter().find(|(c, _, _)| *c == lower) {
+            letters.push(Letter {
+                ch: *base,
+                range: pos..end,
+                tone: Some(*tone),
+                tone_num: None,
+                boundary: false,
+            });
+        } else if lower.is_ascii_lowercase() || lower == 'ü' || lower == 'ê' {
+            letters.push(Letter {
+                ch: if lower == 'v' { 'ü' } else { lower },
+                range: pos..end,
+                tone: None,
+                tone_num: None,
+                boundary: false,
+            });
+        } else if let Some((_, tone)) = COMBINING_TONE_MARKS.iter().find(|(c, _)| *c == ch) {
+            let last = letters.last_mut().filter(|last| last.tone.is_none())?;
+            last.tone = Some(*tone);
+            last.range.end = end;
+        } else if ('0'..='5').contains(&ch) {
+            let last = letters.last_mut().filter(|last| !last.boundary)?;
+            last.tone_num = Some(ch as u8 - b'0');
+            last.range.end = end;
+            last.boundary = true;
+        } else if matches!(ch, '\'' | '’' | '-') || ch.is_whitespace() {
+            if let Some(last) = letters.last_mut() {
+                last.boundary = true;
+            }
+        } else {
+            return None;
+        }
+    }
+    Some(letters)
+}
+
+/// 列出从第 `start` 个字母开始的所有音节，以及音节之后下一个字母的位置
+fn syllables_at<'a>(
+    input: &'a str,
+    letters: &[Letter],
+    start: usize,
+) -> Vec<(usize, SyllableSpan<'a>)> {
+    let mut spans = vec![];
+    let mut text = String::new();
+    let mut tone = None;
+    for end in start..letters.len().min(start + MAX_SYLLABLE_LEN) {
+        let letter = &letters[end];
+        text.push(letter.ch);
+        // 一个音节最多只能标注一个声调
+        match (tone, letter.tone) {
+            (Some(_), Some(_)) => break,
+            (None, Some(_)) => tone = letter.tone,
+            _ => {}
+        }
+        if let Ok(idx) = SYLLABLE_TABLE.binary_search(&text.as_str()) {
+            let valid_tone = match (tone, letter.tone_num) {
+                (Some(_), Some(_)) => None,
+                (tone, tone_num) => Some(tone.or(tone_num)),
+            };
+            if let Some(tone) = valid_tone {
+                let range = letters[start].range.start..letter.range.end;
+                spans.push((
+                    end + 1,
+                    SyllableSpan {
+                        text: &input[range.clone()],
+                        range,
+                        syllable: SYLLABLE_TABLE[idx],
+                        tone,
+                    },
+                ));
+            }
+        }
+        if letter.boundary {
+            break;
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{segment_pinyin, MAX_RESULTS};
+
+    fn to_vec(input: &str) -> Vec<Vec<&'static str>> {
+        segment_pinyin(input)
+            .into_iter()
+            .map(|spans| spans.into_iter().map(|span| span.syllable).collect())
+            .collect()
+    }
+
+    #[test]
+    fn ranking() {
+        assert_eq!(to_vec("fangan")[0], vec!["fan", "gan"]);
+        assert_eq!(to_vec("fang'an")[0], vec!["fang", "an"]);
+        assert_eq!(to_vec("zhongguoren")[0], vec!["zhong", "guo", "ren"]);
+    }
+
+    #[test]
+    fn separators() {
+        assert_eq!(to_vec("Xi An")[0], vec!["xi", "an"]);
+        assert_eq!(to_vec("xi-an")[0], vec!["xi", "an"]);
+        assert_eq!(to_vec("xi’an")[0], vec!["xi", "an"]);
+        assert!(to_vec("xi'an").iter().all(|spans| spans[0] == "xi"));
+    }
+
+    #[test]
+    fn tone() {
+        let spans = segment_pinyin("zhong1guo2").remove(0);
+        assert_eq!(spans[0].text, "zhong1");
+        assert_eq!(spans[0].range, 0..6);
+        assert_eq!(spans[0].tone, Some(1));
+        assert_eq!(spans[1].tone, Some(2));
+        assert!(to_vec("zho1ng").is_empty());
+        assert_eq!(to_vec("xīàn")[0], vec!["xi", "an"]);
+        // 组合用声调符号
+        let spans = segment_pinyin("xi\u{304}'an").remove(0);
+        assert_eq!(spans[0].text, "xi\u{304}");
+        assert_eq!(spans[0].tone, Some(1));
+    }
+
+    #[test]
+    fn umlaut() {
+        assert_eq!(to_vec("lvse")[0], vec!["lü", "se"]);
+        assert_eq!(to_vec("nǚ")[0], vec!["nü"]);
+    }
+
+    #[test]
+    fn long_input() {
+        // 12 个 xian 有 4096 种切分方式，只返回排名最靠前的若干种
+        let results = to_vec(&"xian".repeat(12));
+        assert_eq!(results.len(), MAX_RESULTS);
+        assert_eq!(results[0], vec!["xian"; 12]);
+        assert!(results
+            .iter()
+            .all(|spans| spans.concat() == "xian".repeat(12)));
+        let results = to_vec(&"xian".repeat(200));
+        assert_eq!(results.len(), MAX_RESULTS);
+        assert_eq!(results[0], vec!["xian"; 200]);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(to_vec("").is_empty());
+        assert!(to_vec("xyz").is_empty());
+        assert!(to_vec("中国").is_empty());
+        assert!(to_vec("1xi").is_empty());
+    }
+}
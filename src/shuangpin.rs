@@ -0,0 +1,366 @@
+use crate::data::SYLLABLE_TABLE;
+use crate::Pinyin;
+
+/// 微软双拼
+const MICROSOFT: &str = "q:iu w:ia,ua r:uan,er t:ue y:uai,ü u:sh i:ch o:uo p:un s:iong,ong \
+                         d:iang,uang f:en g:eng h:ang j:an k:ao l:ai ;:ing z:ei x:ie c:iao \
+                         v:zh,ui,üe b:ou n:in m:ian";
+/// 自然码
+const ZIRANMA: &str = "q:iu w:ia,ua r:uan t:ue,üe y:uai,ing u:sh i:ch o:uo p:un s:iong,ong \
+                       d:iang,uang f:en g:eng h:ang j:an k:ao l:ai z:ei x:ie c:iao v:zh,ui,ü \
+                       b:ou n:in m:ian";
+/// 小鹤双拼
+const XIAOHE: &str = "q:iu w:ei r:uan t:ue,üe y:un u:sh i:ch o:uo p:ie s:iong,ong d:ai f:en \
+                      g:eng h:ang j:an k:ing,uai l:iang,uang z:ou x:ia,ua c:ao v:zh,ui,ü \
+                      b:in n:iao m:ian";
+/// 搜狗双拼
+const SOGOU: &str = "q:iu w:ia,ua r:uan,er t:ue,üe y:uai,ü u:sh i:ch o:uo p:un s:iong,ong \
+                     d:iang,uang f:en g:eng h:ang j:an k:ao l:ai ;:ing z:ei x:ie c:iao v:zh,ui \
+                     b:ou n:in m:ian";
+/// 拼音加加
+const PINYIN_JIAJIA: &str = "q:er,ing w:ei r:en t:eng y:iong,ong u:ch i:sh o:uo p:ou s:ai d:ao \
+                             f:an g:ang h:iang,uang j:ian k:iao l:in z:un x:uai,ue,üe c:uan \
+                             v:zh,ui,ü b:ia,ua n:iu m:ie";
+
+/// 双拼布局中可以使用的韵母
+const FINALS: &[&str] = &[
+    "a", "o", "e", "i", "u", "ü", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "ong", "er",
+    "ia", "ie", "iao", "iu", "ian", "in", "iang", "ing", "iong", "ua", "uo", "uai", "ui", "uan",
+    "un", "uang", "ue", "üe",
+];
+
+/// 声母的字母，`y`、`w` 在双拼中也作为声母
+const INITIAL_LETTERS: &str = "bpmfdtnlgkhjqxrzcsyw";
+
+/// 零声母音节的输入方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZeroInitial {
+    /// 以固定的键作为声母，再输入韵母键，如微软双拼中 `an` 为 `oj`
+    Key(char),
+    /// 单字母韵母双写，双字母韵母按拼写输入，更长的韵母输入首字母和韵母键，
+    /// 如自然码中 `a`、`an`、`ang` 分别为 `aa`、`an`、`ah`
+    Spelled,
+    /// 输入韵母的首字母和韵母键，如拼音加加中 `an` 为 `af`
+    FirstLetter,
+}
+
+/// 双拼方案
+///
+/// 方案由布局和零声母的输入方式组成。布局以空白分隔多个 `键:项目` 条目，
+/// 项目为 `zh`、`ch`、`sh` 或者韵母，同一键上的多个项目以 `,` 分隔，如 `v:zh,ui`；
+/// 韵母中的 `ü` 也可以写作 `v`。单字母韵母 `a`、`o`、`e`、`i`、`u` 默认使用同名的键。
+///
+/// 韵母按拼写区分，如 `jue` 的韵母为 `ue`，`lüe` 的韵母为 `üe`。
+///
+/// *仅在启用 `shuangpin` 特性时可用*
+/// ```
+/// use pinyin::{ShuangpinScheme, ToPinyin};
+/// let scheme = ShuangpinScheme::xiaohe();
+/// let codes: Vec<_> = "双拼"
+///     .to_pinyin()
+///     .map(|pinyin| scheme.encode(pinyin.unwrap()).unwrap())
+///     .collect();
+/// assert_eq!(codes, vec!["ul", "pb"]);
+/// assert_eq!(scheme.decode("ulpb"), Some(vec!["shuang", "pin"]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShuangpinScheme {
+    /// `zh`、`ch`、`sh` 的键
+    initials: [char; 3],
+    finals: Vec<(String, char)>,
+    zero_initial: ZeroInitial,
+}
+
+impl ShuangpinScheme {
+    /// 微软双拼
+    pub fn microsoft() -> ShuangpinScheme {
+        Self::builtin(MICROSOFT, ZeroInitial::Key('o'))
+    }
+
+    /// 自然码
+    pub fn ziranma() -> ShuangpinScheme {
+        Self::builtin(ZIRANMA, ZeroInitial::Spelled)
+    }
+
+    /// 小鹤双拼
+    pub fn xiaohe() -> ShuangpinScheme {
+        Self::builtin(XIAOHE, ZeroInitial::Spelled)
+    }
+
+    /// 搜狗双拼
+    pub fn sogou() -> ShuangpinScheme {
+        Self::builtin(SOGOU, ZeroInitial::Key('o'))
+    }
+
+    /// 拼音加加
+    pub fn pinyin_jiajia() -> ShuangpinScheme {
+        Self::builtin(PINYIN_JIAJIA, ZeroInitial::FirstLetter)
+    }
+
+    fn builtin(layout: &str, zero_initial: ZeroInitial) -> ShuangpinScheme {
+        Self::from_layout(layout, zero_initial).unwrap()
+    }
+
+    /// 由布局创建自定义方案，布局格式参见 [`ShuangpinScheme`]
+    ///
+    /// 布局中有无法识别的条目，或者没有给出 `zh`、`ch`、`sh` 的键时返回 `None`。
+    /// ```
+    /// use pinyin::{ShuangpinScheme, ZeroInitial};
+    /// let scheme = ShuangpinScheme::from_layout("v:zh i:ch u:sh h:ang", ZeroInitial::Key('o'));
+    /// assert_eq!(scheme.unwrap().decode("uhoh"), Some(vec!["shang", "ang"]));
+    /// assert_eq!(ShuangpinScheme::from_layout("v:zh", ZeroInitial::Spelled), None);
+    /// ```
+    pub fn from_layout(layout: &str, zero_initial: ZeroInitial) -> Option<ShuangpinScheme> {
+        let mut initials = [None; 3];
+        let mut finals = vec![];
+        for entry in layout.split_whitespace() {
+            let (key, items) = entry.split_once(':')?;
+            let mut key_chars = key.chars();
+            let key = key_chars.next().filter(|key| is_key(*key))?;
+            if key_chars.next().is_some() {
+                return None;
+            }
+            for item in items.split(',') {
+                let item = item.replace('v', "ü");
+                match item.as_str() {
+                    "zh" => initials[0] = Some(key),
+                    "ch" => initials[1] = Some(key),
+                    "sh" => initials[2] = Some(key),
+                    _ if FINALS.contains(&item.as_str()) => finals.push((item, key)),
+                    _ => return None,
+                }
+            }
+        }
+        for vowel in ["a", "o", "e", "i", "u"] {
+            if finals.iter().all(|(final_, _)| final_ != vowel) {
+                finals.push((vowel.to_string(), vowel.chars().next().unwrap()));
+            }
+        }
+        Some(ShuangpinScheme {
+            initials: [initials[0]?, initials[1]?, initials[2]?],
+            finals,
+            zero_initial,
+        })
+    }
+
+    /// 返回拼音的双拼编码，无法用本方案输入的音节（如 `ê`、`hm`）返回 `None`
+    pub fn encode(&self, pinyin: Pinyin) -> Option<String> {
+        let syllable = pinyin.plain();
+        let (initial, final_) = split_syllable(syllable);
+        let initial_key = match initial {
+            "zh" => self.initials[0],
+            "ch" => self.initials[1],
+            "sh" => self.initials[2],
+            "" => match self.zero_initial {
+                ZeroInitial::Key(key) => key,
+                ZeroInitial::Spelled if matches!(final_, "a" | "o" | "e") => {
+                    return Some(final_.repeat(2));
+                }
+                ZeroInitial::Spelled if final_.chars().count() == 2 => {
+                    return Some(final_.to_string());
+                }
+                ZeroInitial::Spelled | ZeroInitial::FirstLetter => final_.chars().next()?,
+            },
+            _ => initial.chars().next()?,
+        };
+        let final_key = self.final_key(final_)?;
+        Some([initial_key, final_key].iter().collect())
+    }
+
+    /// 将双拼编码还原为不带声调的音节，编码长度不是偶数或者含有无效的编码时返回 `None`
+    pub fn decode(&self, keys: &str) -> Option<Vec<&'static str>> {
+        let keys: Vec<char> = keys.chars().map(|key| key.to_ascii_lowercase()).collect();
+        if !keys.len().is_multiple_of(2) {
+            return None;
+        }
+        keys.chunks(2)
+            .map(|pair| self.decode_pair(pair[0], pair[1]))
+            .collect()
+    }
+
+    fn decode_pair(&self, first: char, second: char) -> Option<&'static str> {
+        let finals = self
+            .finals
+            .iter()
+            .filter(move |(_, key)| *key == second)
+            .map(|(final_, _)| final_.as_str());
+        let mut initials = vec![];
+        for (initial, key) in ["zh", "ch", "sh"].iter().zip(self.initials.iter()) {
+            if *key == first {
+                initials.push(initial.to_string());
+            }
+        }
+        if INITIAL_LETTERS.contains(first) {
+            initials.push(first.to_string());
+        }
+        let candidates = initials.iter().flat_map(|initial| {
+            finals
+                .clone()
+                .map(move |final_| format!("{initial}{final_}"))
+        });
+        let zero: Vec<String> = match self.zero_initial {
+            ZeroInitial::Key(key) if key == first => finals.clone().map(String::from).collect(),
+            ZeroInitial::Spelled if matches!(first, 'a' | 'o' | 'e') => {
+                let spelled = if first == second {
+                    first.to_string()
+                } else {
+                    [first, second].iter().collect()
+                };
+                std::iter::once(spelled)
+                    .chain(
+                        finals
+                            .clone()
+                            .filter(|final_| final_.len() > 2 && final_.starts_with(first))
+                            .map(String::from),
+                    )
+                    .collect()
+            }
+            ZeroInitial::FirstLetter if matches!(first, 'a' | 'o' | 'e') => finals
+                .clone()
+                .filter(|final_| final_.starts_with(first))
+                .map(String::from)
+                .collect(),
+            _ => vec![],
+        };
+        candidates.chain(zero).find_map(|syllable| {
+            SYLLABLE_TABLE
+                .binary_search(&syllable.as_str())
+                .ok()
+                .map(|idx| SYLLABLE_TABLE[idx])
+        })
+    }
+
+    fn final_key(&self, final_: &str) -> Option<char> {
+        self.finals
+            .iter()
+            .find(|(item, _)| item == final_)
+            .map(|(_, key)| *key)
+    }
+}
+
+fn is_key(key: char) -> bool {
+    key.is_ascii_lowercase() || key == ';'
+}
+
+/// 按拼写将音节拆分为声母和韵母，`y`、`w` 也作为声母
+fn split_syllable(syllable: &str) -> (&str, &str) {
+    let len = if ["zh", "ch", "sh"].iter().any(|s| syllable.starts_with(s)) {
+        2
+    } else if syllable.starts_with(|ch| INITIAL_LETTERS.contains(ch)) {
+        1
+    } else {
+        0
+    };
+    syllable.split_at(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_syllable, ShuangpinScheme, ZeroInitial};
+    use crate::ToPinyin;
+
+    fn encode(scheme: &ShuangpinScheme, input: &str) -> Vec<Option<String>> {
+        input
+            .to_pinyin()
+            .map(|pinyin| scheme.encode(pinyin.unwrap()))
+            .collect()
+    }
+
+    fn codes(codes: &[&str]) -> Vec<Option<String>> {
+        codes.iter().map(|code| Some(code.to_string())).collect()
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(split_syllable("zhuang"), ("zh", "uang"));
+        assert_eq!(split_syllable("yue"), ("y", "ue"));
+        assert_eq!(split_syllable("lüe"), ("l", "üe"));
+        assert_eq!(split_syllable("ang"), ("", "ang"));
+    }
+
+    #[test]
+    fn builtin_schemes() {
+        let input = "中国人学习双拼女";
+        let cases = [
+            (
+                ShuangpinScheme::microsoft(),
+                ["vs", "go", "rf", "xt", "xi", "ud", "pn", "ny"],
+            ),
+            (
+                ShuangpinScheme::ziranma(),
+                ["vs", "go", "rf", "xt", "xi", "ud", "pn", "nv"],
+            ),
+            (
+                ShuangpinScheme::xiaohe(),
+                ["vs", "go", "rf", "xt", "xi", "ul", "pb", "nv"],
+            ),
+            (
+                ShuangpinScheme::sogou(),
+                ["vs", "go", "rf", "xt", "xi", "ud", "pn", "ny"],
+            ),
+            (
+                ShuangpinScheme::pinyin_jiajia(),
+                ["vy", "go", "rr", "xx", "xi", "ih", "pl", "nv"],
+            ),
+        ];
+        for (scheme, expected) in cases.iter() {
+            assert_eq!(encode(scheme, input), codes(expected), "{scheme:?}");
+            let keys = expected.concat();
+            assert_eq!(
+                scheme.decode(&keys),
+                Some(vec![
+                    "zhong", "guo", "ren", "xue", "xi", "shuang", "pin", "nü"
+                ]),
+                "{scheme:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn zero_initial() {
+        let input = "爱安饿二欧";
+        let cases = [
+            (ShuangpinScheme::microsoft(), ["ol", "oj", "oe", "or", "ob"]),
+            (ShuangpinScheme::ziranma(), ["ai", "an", "ee", "er", "ou"]),
+            (
+                ShuangpinScheme::pinyin_jiajia(),
+                ["as", "af", "ee", "eq", "op"],
+            ),
+        ];
+        for (scheme, expected) in cases.iter() {
+            assert_eq!(encode(scheme, input), codes(expected), "{scheme:?}");
+            assert_eq!(
+                scheme.decode(&expected.concat()),
+                Some(vec!["ai", "an", "e", "er", "ou"]),
+                "{scheme:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let scheme = ShuangpinScheme::microsoft();
+        // 同一键上的韵母由声母区分
+        assert_eq!(scheme.decode("hwjw"), Some(vec!["hua", "jia"]));
+        assert_eq!(scheme.decode("HW"), Some(vec!["hua"]));
+        assert_eq!(scheme.decode("h"), None);
+        assert_eq!(scheme.decode("bz;q"), None);
+        assert_eq!(scheme.decode(""), Some(vec![]));
+    }
+
+    #[test]
+    fn custom_scheme() {
+        let scheme =
+            ShuangpinScheme::from_layout("v:zh i:ch u:sh t:ue", ZeroInitial::Spelled).unwrap();
+        assert_eq!(encode(&scheme, "是学"), codes(&["ui", "xt"]));
+        assert_eq!(encode(&scheme, "女"), vec![None]);
+        assert_eq!(
+            ShuangpinScheme::from_layout("ab:zh", ZeroInitial::Spelled),
+            None
+        );
+        assert_eq!(
+            ShuangpinScheme::from_layout("v:zh i:ch u:sh v:xyz", ZeroInitial::Spelled),
+            None
+        );
+    }
+}
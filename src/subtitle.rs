@@ -0,0 +1,585 @@
+use crate::interlinear::char_width;
+use crate::{Pinyin, ToPinyin};
+use std::collections::HashMap;
+
+/// 未指定 `PlayResX`、`PlayResY` 时的画面大小
+const DEFAULT_PLAY_RES: (u32, u32) = (384, 288);
+
+/// ASS 样式中与拼音排版相关的属性
+#[derive(Clone, Copy)]
+struct AssStyle {
+    font_size: u32,
+    margin_l: u32,
+    margin_r: u32,
+    margin_v: u32,
+    /// 小键盘式的对齐方式，`1` 到 `9`
+    alignment: u32,
+}
+
+impl Default for AssStyle {
+    fn default() -> Self {
+        AssStyle {
+            font_size: 20,
+            margin_l: 10,
+            margin_r: 10,
+            margin_v: 10,
+            alignment: 2,
+        }
+    }
+}
+
+/// 排版时原字幕中的一个字
+struct Glyph {
+    /// 横向占用的宽度
+    advance: u32,
+    pinyin: Option<Pinyin>,
+    /// 卡拉 OK 时长，单位为厘秒
+    karaoke: Option<u32>,
+}
+
+/// 拼音行中各项内容的类别，用于决定是否以空格分隔
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Syllable,
+    /// 字母或数字
+    Word,
+    /// 前括号、前引号以及 HTML 标签的尖括号等
+    Opening,
+    /// 其它标点符号
+    Closing,
+    Space,
+}
+
+/// ASS 字幕文本中的一项
+enum AssItem<'a> {
+    /// 样式代码，如 `{\b1}`
+    Override(&'a str),
+    /// 转义序列，如 `\N`
+    Escape(&'a str),
+    Char(char),
+}
+
+/// 字幕注音工具，为 SRT 和 ASS 字幕中的中文添加拼音
+/// ```
+/// # #[cfg(feature = "with_tone")] {
+/// use pinyin::{Pinyin, SubtitleAnnotator};
+/// let annotator = SubtitleAnnotator::new(Pinyin::with_tone);
+/// let srt = "1\n00:00:01,000 --> 00:00:02,000\n你好，世界\n";
+/// assert_eq!(
+///     annotator.annotate_srt(srt),
+///     "1\n00:00:01,000 --> 00:00:02,000\nnǐ hǎo, shì jiè\n你好，世界\n"
+/// );
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SubtitleAnnotator<F> {
+    style: F,
+}
+
+impl<F> SubtitleAnnotator<F>
+where
+    F: Fn(Pinyin) -> &'static str,
+{
+    /// 使用给定的拼音风格创建注音工具，如 [`Pinyin::with_tone`]
+    pub fn new(style: F) -> SubtitleAnnotator<F> {
+        SubtitleAnnotator { style }
+    }
+
+    /// 为 SRT 字幕添加拼音
+    ///
+    /// 在每条字幕中每一行含有汉字的文本之上插入对应的拼音行，序号和时间轴保持不变。
+    pub fn annotate_srt(&self, input: &str) -> String {
+        let mut lines = vec![];
+        let mut in_text = false;
+        for line in input.lines() {
+            if line.trim().is_empty() {
+                in_text = false;
+            } else if in_text && has_pinyin(line) {
+                lines.push(self.pinyin_line(line));
+            } else if line.contains("-->") {
+                in_text = true;
+            }
+            lines.push(line.to_string());
+        }
+        join_lines(input, lines)
+    }
+
+    /// 为 ASS/SSA 字幕添加拼音
+    ///
+    /// 在每个含有汉字的 `Dialogue` 事件之后，为其中每个有拼音的字插入一个时间相同的拼音事件，
+    /// 原事件保持不变。拼音事件使用原事件的样式，字号缩小为原样式的六成，
+    /// 通过 `\an2\pos` 放在对应的字的正上方。
+    ///
+    /// 字的位置根据画面大小 `PlayResX`、`PlayResY`，样式的字号、对齐方式和边距推算：
+    /// 全角字符宽度等于字号，其它字符为字号的一半，行高等于字号，`\N` 换行。
+    /// 原字幕中改变字号、位置的样式代码不参与推算。
+    ///
+    /// 拼音事件都会加上卡拉 OK 标签 `\k`，原字幕的时长在各字之间平均分配，
+    /// 每个拼音事件先用一个空的 `\k` 等待前面各字的时长。
+    /// 原字幕已经含有卡拉 OK 标签时，拼音事件不再添加 `\k`。
+    /// ```
+    /// # #[cfg(feature = "plain")] {
+    /// use pinyin::{Pinyin, SubtitleAnnotator};
+    /// let annotator = SubtitleAnnotator::new(Pinyin::plain);
+    /// let ass = "\
+    /// [V4+ Styles]
+    /// Format: Name, Fontname, Fontsize, Alignment, MarginV
+    /// Style: Default,Arial,40,2,20
+    ///
+    /// [Events]
+    /// Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+    /// Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,{\\b1}你好
+    /// ";
+    /// assert_eq!(
+    ///     annotator.annotate_ass(ass),
+    ///     "\
+    /// [V4+ Styles]
+    /// Format: Name, Fontname, Fontsize, Alignment, MarginV
+    /// Style: Default,Arial,40,2,20
+    ///
+    /// [Events]
+    /// Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+    /// Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,{\\b1}你好
+    /// Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,{\\an2\\pos(172,228)\\fs24\\k50}ni
+    /// Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,{\\an2\\pos(212,228)\\fs24\\k50}{\\k50}hao
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn annotate_ass(&self, input: &str) -> String {
+        let mut lines = vec![];
+        let mut section = "";
+        let mut styles = HashMap::new();
+        let mut style_format = vec![];
+        let mut event_format = vec![];
+        let (mut play_res_x, mut play_res_y) = (None, None);
+        for line in input.lines() {
+            let trimmed = line.trim();
+            if trimmed.starts_with('[') {
+                section = trimmed;
+            } else if let Some(value) = trimmed.strip_prefix("PlayResX:") {
+                play_res_x = value.trim().parse::<u32>().ok();
+            } else if let Some(value) = trimmed.strip_prefix("PlayResY:") {
+                play_res_y = value.trim().parse::<u32>().ok();
+            } else if let Some(format) = trimmed.strip_prefix("Format:") {
+                let format = format.split(',').map(|f| f.trim().to_string()).collect();
+                if section == "[Events]" {
+                    event_format = format;
+                } else {
+                    style_format = format;
+                }
+            } else if let Some(style) = trimmed.strip_prefix("Style:") {
+                if let Some((name, style)) = parse_style(&style_format, style) {
+                    styles.insert(name, style);
+                }
+            } else if let Some(event) = trimmed.strip_prefix("Dialogue:") {
+                // 只给出一个方向时按 4:3 推算另一个方向
+                let play_res = match (play_res_x, play_res_y) {
+                    (Some(x), Some(y)) => (x, y),
+                    (Some(x), None) => (x, x * 3 / 4),
+                    (None, Some(y)) => (y * 4 / 3, y),
+                    (None, None) => DEFAULT_PLAY_RES,
+                };
+                let events = self.annotate_event(&event_format, &styles, play_res, event);
+                if !events.is_empty() {
+                    lines.push(line.to_string());
+                    lines.extend(events.into_iter().map(|event| format!("Dialogue:{event}")));
+                    continue;
+                }
+            }
+            lines.push(line.to_string());
+        }
+        join_lines(input, lines)
+    }
+
+    /// 生成 SRT 的拼音行
+    fn pinyin_line(&self, line: &str) -> String {
+        let mut result = String::new();
+        let mut last = Token::Space;
+        for (ch, pinyin) in line.chars().zip(line.to_pinyin()) {
+            last = self.push_token(&mut result, last, ch, pinyin);
+        }
+        result.trim_end().to_string()
+    }
+
+    /// 向拼音行追加一个字对应的内容，返回其类别
+    ///
+    /// 音节之间以及音节与字母、数字之间以空格分隔，标点紧接在前一个音节之后。
+    fn push_token(
+        &self,
+        output: &mut String,
+        last: Token,
+        ch: char,
+        pinyin: Option<Pinyin>,
+    ) -> Token {
+        let token = match pinyin {
+            Some(_) => Token::Syllable,
+            None if ch.is_whitespace() => Token::Space,
+            None if ch.is_alphanumeric() => Token::Word,
+            None if matches!(ch, '(' | '[' | '<' | '>' | '"' | '\'' | '“' | '‘' | '（') => {
+                Token::Opening
+            }
+            None => Token::Closing,
+        };
+        let spaced = match token {
+            Token::Syllable => matches!(last, Token::Syllable | Token::Word | Token::Closing),
+            Token::Word => last == Token::Syllable,
+            _ => false,
+        };
+        if spaced {
+            output.push(' ');
+        }
+        match pinyin {
+            Some(pinyin) => output.push_str((self.style)(pinyin)),
+            None => output.push(to_latin_punctuation(ch)),
+        }
+        token
+    }
+
+    /// 生成一个 `Dialogue` 事件中各字对应的拼音事件
+    fn annotate_event(
+        &self,
+        format: &[String],
+        styles: &HashMap<String, AssStyle>,
+        play_res: (u32, u32),
+        event: &str,
+    ) -> Vec<String> {
+        let field = |name: &str| format.iter().position(|f| f.eq_ignore_ascii_case(name));
+        let text_idx = match field("Text") {
+            Some(idx) if idx + 1 == format.len() => idx,
+            _ => return vec![],
+        };
+        let fields = event.splitn(format.len(), ',').collect::<Vec<_>>();
+        if fields.len() != format.len() {
+            return vec![];
+        }
+        let items = parse_ass_text(fields[text_idx]);
+        let has_pinyin = items
+            .iter()
+            .any(|item| matches!(item, AssItem::Char(ch) if ch.to_pinyin().is_some()));
+        if !has_pinyin {
+            return vec![];
+        }
+
+        let style_name = field("Style").map_or("Default", |idx| fields[idx].trim());
+        let style = styles
+            .get(style_name.trim_start_matches('*'))
+            .copied()
+            .unwrap_or_default();
+        let duration = match (field("Start"), field("End")) {
+            (Some(start), Some(end)) => parse_ass_time(fields[end])
+                .zip(parse_ass_time(fields[start]))
+                .and_then(|(end, start)| end.checked_sub(start)),
+            _ => None,
+        };
+        let has_karaoke = items.iter().any(|item| {
+            matches!(item, AssItem::Override(code) if code.to_ascii_lowercase().contains("\\k"))
+        });
+        let visible = items
+            .iter()
+            .filter(|item| matches!(item, AssItem::Char(ch) if !ch.is_whitespace()))
+            .count();
+        let karaoke = match duration {
+            Some(duration) if !has_karaoke => {
+                let count = visible as u32;
+                (0..count)
+                    .map(|i| duration / count + u32::from(i < duration % count))
+                    .collect()
+            }
+            _ => vec![],
+        };
+        let mut karaoke = karaoke.into_iter();
+
+        // 将原字幕按行切分为字
+        let font_size = style.font_size;
+        let half = font_size / 2;
+        let mut lines: Vec<Vec<Glyph>> = vec![vec![]];
+        for item in items.iter() {
+            let glyph = match item {
+                AssItem::Override(_) => continue,
+                AssItem::Escape("\\N") => {
+                    lines.push(vec![]);
+                    continue;
+                }
+                AssItem::Escape("\\h") => Glyph {
+                    advance: half,
+                    pinyin: None,
+                    karaoke: None,
+                },
+                AssItem::Escape(_) => continue,
+                AssItem::Char(ch) if ch.is_whitespace() => Glyph {
+                    advance: half,
+                    pinyin: None,
+                    karaoke: None,
+                },
+                AssItem::Char(ch) => Glyph {
+                    advance: half * char_width(*ch) as u32,
+                    pinyin: ch.to_pinyin(),
+                    karaoke: karaoke.next(),
+                },
+            };
+            lines.last_mut().unwrap().push(glyph);
+        }
+
+        let margin = |name: &str, default: u32| {
+            field(name)
+                .and_then(|idx| fields[idx].trim().parse::<u32>().ok())
+                .filter(|margin| *margin > 0)
+                .unwrap_or(default)
+        };
+        let margin_l = margin("MarginL", style.margin_l);
+        let margin_r = margin("MarginR", style.margin_r);
+        let margin_v = margin("MarginV", style.margin_v);
+        let (res_x, res_y) = play_res;
+        let alignment = Some(style.alignment)
+            .filter(|alignment| (1..=9).contains(alignment))
+            .unwrap_or(2);
+        let block_height = font_size * lines.len() as u32;
+        let block_top = match (alignment - 1) / 3 {
+            0 => res_y.saturating_sub(margin_v + block_height),
+            1 => res_y.saturating_sub(block_height) / 2,
+            _ => margin_v,
+        };
+
+        let pinyin_size = (font_size * 3 + 2) / 5;
+        let mut events = vec![];
+        let mut offset = 0;
+        for (i, line) in lines.iter().enumerate() {
+            let width = line.iter().map(|glyph| glyph.advance).sum::<u32>();
+            let mut x = match (alignment - 1) % 3 {
+                0 => margin_l,
+                1 => margin_l + res_x.saturating_sub(margin_l + margin_r + width) / 2,
+                _ => res_x.saturating_sub(margin_r + width),
+            };
+            // 拼音的底边与字的顶边对齐
+            let y = block_top + font_size * i as u32;
+            for glyph in line {
+                if let Some(pinyin) = glyph.pinyin {
+                    let center = x + glyph.advance / 2;
+                    let mut text = format!("{{\\an2\\pos({center},{y})\\fs{pinyin_size}");
+                    if let Some(k) = glyph.karaoke {
+                        if offset > 0 {
+                            text.push_str(&format!("\\k{offset}}}{{"));
+                        }
+                        text.push_str(&format!("\\k{k}"));
+                    }
+                    text.push('}');
+                    text.push_str((self.style)(pinyin));
+                    let mut event_fields = fields.clone();
+                    event_fields[text_idx] = &text;
+                    events.push(event_fields.join(","));
+                }
+                offset += glyph.karaoke.unwrap_or(0);
+                x += glyph.advance;
+            }
+        }
+        events
+    }
+}
+
+fn has_pinyin(line: &str) -> bool {
+    line.to_pinyin().any(|pinyin| pinyin.is_some())
+}
+
+/// 将中文标点转换为拼音行中使用的西文标点
+fn to_latin_punctuation(ch: char) -> char {
+    match ch {
+        '，' | '、' => ',',
+        '。' => '.',
+        '？' => '?',
+        '！' => '!',
+        '：' => ':',
+        '；' => ';',
+        '“' | '”' => '"',
+        '‘' | '’' => '\'',
+        '（' => '(',
+        '）' => ')',
+        _ => ch,
+    }
+}
+
+/// 按照原输入的换行符和结尾换行拼接各行
+fn join_lines(input: &str, lines: Vec<String>) -> String {
+    let newline = if input.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut result = lines.join(newline);
+    if input.ends_with('\n') {
+        result.push_str(newline);
+    }
+    result
+}
+
+/// 解析 `Style:` 行，返回样式名及排版属性
+fn parse_style(format: &[String], style: &str) -> Option<(String, AssStyle)> {
+    let fields = style.split(',').map(str::trim).collect::<Vec<_>>();
+    let field = |name: &str| {
+        format
+            .iter()
+            .position(|f| f.eq_ignore_ascii_case(name))
+            .and_then(|idx| fields.get(idx))
+    };
+    let number = |name: &str| {
+        field(name)
+            .and_then(|value| value.parse::<f64>().ok())
+            .map(|value| value.round() as u32)
+    };
+    let default = AssStyle::default();
+    let style = AssStyle {
+        font_size: number("Fontsize").unwrap_or(default.font_size),
+        margin_l: number("MarginL").unwrap_or(default.margin_l),
+        margin_r: number("MarginR").unwrap_or(default.margin_r),
+        margin_v: number("MarginV").unwrap_or(default.margin_v),
+        alignment: number("Alignment").unwrap_or(default.alignment),
+    };
+    Some((field("Name")?.to_string(), style))
+}
+
+/// 解析 `H:MM:SS.cc` 形式的时间，返回厘秒数
+///
+/// 小数部分按小数处理，如 `.5` 为 50 厘秒，超过两位的部分舍去。
+fn parse_ass_time(time: &str) -> Option<u32> {
+    let mut parts = time.trim().split(':');
+    let hours = parts.next()?.parse::<u32>().ok()?;
+    let minutes = parts.next()?.parse::<u32>().ok()?;
+    let (seconds, fraction) = parts.next()?.split_once('.')?;
+    if parts.next().is_some()
+        || fraction.is_empty()
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let seconds = seconds.parse::<u32>().ok()?;
+    let centis = format!("{fraction:0<2}")[..2].parse::<u32>().ok()?;
+    Some(((hours * 60 + minutes) * 60 + seconds) * 100 + centis)
+}
+
+fn parse_ass_text(text: &str) -> Vec<AssItem<'_>> {
+    let mut items = vec![];
+    let mut rest = text;
+    while let Some(ch) = rest.chars().next() {
+        let len = match ch {
+            '{' => match rest.find('}') {
+                Some(end) => {
+                    items.push(AssItem::Override(&rest[..=end]));
+                    end + 1
+                }
+                None => {
+                    items.push(AssItem::Char(ch));
+                    1
+                }
+            },
+            '\\' if rest[1..].starts_with(['N', 'n', 'h']) => {
+                items.push(AssItem::Escape(&rest[..2]));
+                2
+            }
+            _ => {
+                items.push(AssItem::Char(ch));
+                ch.len_utf8()
+            }
+        };
+        rest = &rest[len..];
+    }
+    items
+}
+
+#[cfg(test)]
+#[cfg(feature = "plain")]
+mod tests {
+    use crate::{Pinyin, SubtitleAnnotator};
+
+    #[test]
+    fn srt() {
+        let annotator = SubtitleAnnotator::new(Pinyin::plain);
+        let input = "1\r\n00:00:01,000 --> 00:00:02,500\r\n<i>你好</i>\r\nHello\r\n\r\n\
+                     2\r\n00:00:03,000 --> 00:00:04,000\r\n中国 2024\r\n";
+        assert_eq!(
+            annotator.annotate_srt(input),
+            "1\r\n00:00:01,000 --> 00:00:02,500\r\n<i>ni hao</i>\r\n<i>你好</i>\r\nHello\r\n\r\n\
+             2\r\n00:00:03,000 --> 00:00:04,000\r\nzhong guo 2024\r\n中国 2024\r\n"
+        );
+    }
+
+    #[test]
+    fn ass_karaoke_split() {
+        let annotator = SubtitleAnnotator::new(Pinyin::plain);
+        let input = "[Events]\n\
+                     Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n\
+                     Dialogue: 1,0:00:00.00,0:00:01.00,Default,,0,0,0,,你好，a\\N中国\n";
+        let output = annotator.annotate_ass(input);
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines[2], input.lines().nth(2).unwrap());
+        // 默认画面为 384x288，字号 20，第二行在第一行之下 20 像素
+        assert_eq!(
+            lines[3..],
+            [
+                "Dialogue: 1,0:00:00.00,0:00:01.00,Default,,0,0,0,,{\\an2\\pos(167,238)\\fs12\\k17}ni",
+                "Dialogue: 1,0:00:00.00,0:00:01.00,Default,,0,0,0,,\
+                 {\\an2\\pos(187,238)\\fs12\\k17}{\\k17}hao",
+                "Dialogue: 1,0:00:00.00,0:00:01.00,Default,,0,0,0,,\
+                 {\\an2\\pos(182,258)\\fs12\\k68}{\\k16}zhong",
+                "Dialogue: 1,0:00:00.00,0:00:01.00,Default,,0,0,0,,\
+                 {\\an2\\pos(202,258)\\fs12\\k84}{\\k16}guo",
+            ]
+        );
+    }
+
+    #[test]
+    fn ass_positions() {
+        let annotator = SubtitleAnnotator::new(Pinyin::plain);
+        let input = "[Script Info]\nPlayResX: 1920\nPlayResY: 1080\n\n[V4+ Styles]\n\
+                     Format: Name, Fontsize, Alignment, MarginL, MarginR, MarginV\n\
+                     Style: Left,60,1,100,100,50\n\
+                     Style: Right,60,3,100,100,50\n\n[Events]\n\
+                     Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n\
+                     Dialogue: 0,0:00:00.00,0:00:00.02,Left,,0,0,0,,a 中\n\
+                     Dialogue: 0,0:00:00.00,0:00:00.02,Right,,0,200,0,,中国\n";
+        let output = annotator.annotate_ass(input);
+        let lines = output.lines().collect::<Vec<_>>();
+        // 半角字符和空格各占 30 像素，中 的中心在 100 + 60 + 30 处
+        assert_eq!(
+            lines[12],
+            "Dialogue: 0,0:00:00.00,0:00:00.02,Left,,0,0,0,,{\\an2\\pos(190,970)\\fs36\\k1}{\\k1}zhong"
+        );
+        // 事件中的 MarginR 覆盖样式中的边距
+        assert_eq!(
+            lines[14..],
+            [
+                "Dialogue: 0,0:00:00.00,0:00:00.02,Right,,0,200,0,,{\\an2\\pos(1630,970)\\fs36\\k1}zhong",
+                "Dialogue: 0,0:00:00.00,0:00:00.02,Right,,0,200,0,,\
+                 {\\an2\\pos(1690,970)\\fs36\\k1}{\\k1}guo",
+            ]
+        );
+    }
+
+    #[test]
+    fn ass_keeps_other_lines() {
+        let annotator = SubtitleAnnotator::new(Pinyin::plain);
+        let input = "[Script Info]\nTitle: 测试\n\n[V4+ Styles]\n\
+                     Format: Name, Fontsize, Alignment, MarginV\n\
+                     Style: Top,30,8,40\n\n[Events]\n\
+                     Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n\
+                     Comment: 0,0:00:00.00,0:00:01.00,Top,,0,0,0,,中国\n\
+                     Dialogue: 0,0:00:00.00,0:00:01.00,Top,,0,0,0,,Hello\n\
+                     Dialogue: 0,0:00:00.00,0:00:01.00,Top,,0,0,0,,{\\k100}中国";
+        let output = annotator.annotate_ass(input);
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines[..11], input.lines().take(11).collect::<Vec<_>>()[..]);
+        assert_eq!(
+            lines[11..],
+            [
+                "Dialogue: 0,0:00:00.00,0:00:01.00,Top,,0,0,0,,{\\k100}中国",
+                "Dialogue: 0,0:00:00.00,0:00:01.00,Top,,0,0,0,,{\\an2\\pos(177,40)\\fs18}zhong",
+                "Dialogue: 0,0:00:00.00,0:00:01.00,Top,,0,0,0,,{\\an2\\pos(207,40)\\fs18}guo",
+            ]
+        );
+        assert!(!output.ends_with('\n'));
+    }
+
+    #[test]
+    fn ass_time() {
+        assert_eq!(super::parse_ass_time("0:00:01.5"), Some(150));
+        assert_eq!(super::parse_ass_time("0:00:01.50"), Some(150));
+        assert_eq!(super::parse_ass_time("0:00:01.05"), Some(105));
+        assert_eq!(super::parse_ass_time("1:02:03.456"), Some(372345));
+        assert_eq!(super::parse_ass_time("0:00:01."), None);
+    }
+}
@@ -0,0 +1,9 @@
+use crate::data::SURNAME_TABLE;
+use crate::{match_phrase, PhrasePinyinIter};
+
+/// 匹配输入开头的姓氏，返回该姓氏及其作为姓氏时的读音
+///
+/// 只有读音与常用读音不同的姓氏会被匹配到，如：单（shàn）、曾（zēng）。
+pub(crate) fn match_surname(input: &str) -> Option<(&'static str, PhrasePinyinIter)> {
+    match_phrase(SURNAME_TABLE, input)
+}
@@ -0,0 +1,235 @@
+use crate::ruby::push_escaped;
+use crate::{Pinyin, ToPinyin};
+use std::fmt::Write as _;
+#[cfg(feature = "termcolor")]
+use std::io;
+#[cfg(feature = "termcolor")]
+use termcolor::{Color, ColorSpec, WriteColor};
+
+/// 声调配色方案，依次为轻声和第一至第四声的 RGB 颜色
+///
+/// 默认为 [`TonePalette::PLECO`]。
+/// ```
+/// use pinyin::TonePalette;
+/// let palette = TonePalette::new([
+///     (0x80, 0x80, 0x80),
+///     (0xff, 0x00, 0x00),
+///     (0xff, 0xa5, 0x00),
+///     (0x00, 0x80, 0x00),
+///     (0x00, 0x00, 0xff),
+/// ]);
+/// assert_eq!(palette.color(2), (0xff, 0xa5, 0x00));
+/// assert_eq!(palette.color(0), (0x80, 0x80, 0x80));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TonePalette([(u8, u8, u8); 5]);
+
+impl TonePalette {
+    /// Pleco 的配色：一声红、二声绿、三声蓝、四声紫、轻声灰
+    pub const PLECO: TonePalette = TonePalette([
+        (0x77, 0x77, 0x77),
+        (0xe3, 0x00, 0x00),
+        (0x02, 0xb3, 0x1c),
+        (0x15, 0x10, 0xf0),
+        (0x89, 0x00, 0xbf),
+    ]);
+
+    /// MDBG 的配色：一声红、二声橙、三声绿、四声蓝、轻声灰
+    pub const MDBG: TonePalette = TonePalette([
+        (0x80, 0x80, 0x80),
+        (0xff, 0x00, 0x00),
+        (0xd8, 0x90, 0x00),
+        (0x00, 0xa0, 0x00),
+        (0x00, 0x00, 0xff),
+    ]);
+
+    /// 使用给定的颜色创建配色方案，`colors[0]` 为轻声，`colors[1]` 到 `colors[4]` 为四声
+    pub const fn new(colors: [(u8, u8, u8); 5]) -> TonePalette {
+        TonePalette(colors)
+    }
+
+    /// 返回给定声调的颜色，声调与 [`Pinyin::tone`] 相同，`0` 表示轻声
+    pub fn color(&self, tone: u8) -> (u8, u8, u8) {
+        self.0[usize::from(tone.min(4))]
+    }
+}
+
+impl Default for TonePalette {
+    fn default() -> TonePalette {
+        TonePalette::PLECO
+    }
+}
+
+/// 按声调为汉字及其拼音着色的渲染器
+///
+/// 每个字的声调取自其 [`Pinyin`]，颜色由 [`TonePalette`] 决定。
+/// ```
+/// # #[cfg(feature = "with_tone")] {
+/// use pinyin::{Pinyin, TonePalette, ToneColorRenderer};
+/// let renderer = ToneColorRenderer::new(Pinyin::with_tone).palette(TonePalette::MDBG);
+/// assert_eq!(
+///     renderer.render_html("好的"),
+///     concat!(
+///         r#"<span style="color: #00a000"><ruby>好<rt>hǎo</rt></ruby></span>"#,
+///         r#"<span style="color: #808080"><ruby>的<rt>de</rt></ruby></span>"#,
+///     )
+/// );
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ToneColorRenderer<F> {
+    style: F,
+    palette: TonePalette,
+}
+
+impl<F> ToneColorRenderer<F>
+where
+    F: Fn(Pinyin) -> &'static str,
+{
+    /// 使用给定的拼音风格和默认配色创建渲染器
+    pub fn new(style: F) -> ToneColorRenderer<F> {
+        ToneColorRenderer {
+            style,
+            palette: TonePalette::default(),
+        }
+    }
+
+    /// 设置配色方案
+    pub fn palette(mut self, palette: TonePalette) -> ToneColorRenderer<F> {
+        self.palette = palette;
+        self
+    }
+
+    /// 渲染为 HTML，每个有拼音的字生成一个带颜色的 `<span>`，其中包含该字的 `<ruby>` 注音
+    ///
+    /// 其它字符原样输出，所有文本都会进行 HTML 转义。
+    pub fn render_html(&self, input: &str) -> String {
+        let mut output = String::with_capacity(input.len() * 16);
+        for (ch, pinyin) in input.chars().zip(input.to_pinyin()) {
+            let mut buf = [0; 4];
+            let text = ch.encode_utf8(&mut buf);
+            match pinyin {
+                Some(pinyin) => {
+                    let (r, g, b) = self.palette.color(pinyin.tone());
+                    write!(
+                        output,
+                        r#"<span style="color: #{r:02x}{g:02x}{b:02x}"><ruby>"#
+                    )
+                    .unwrap();
+                    push_escaped(&mut output, text);
+                    output.push_str("<rt>");
+                    push_escaped(&mut output, (self.style)(pinyin));
+                    output.push_str("</rt></ruby></span>");
+                }
+                None => push_escaped(&mut output, text),
+            }
+        }
+        output
+    }
+
+    /// 将带颜色的文本写入终端
+    ///
+    /// 每个字按声调着色，连续汉字之后在括号中给出以空格分隔的拼音，拼音同样按声调着色，
+    /// 如 `你好(nǐ hǎo)，世界(shì jiè)！`。
+    ///
+    /// *仅在启用 `termcolor` 特性时可用*
+    /// ```
+    /// # #[cfg(feature = "with_tone")] {
+    /// use pinyin::{Pinyin, ToneColorRenderer};
+    /// use termcolor::{ColorChoice, StandardStream};
+    /// let renderer = ToneColorRenderer::new(Pinyin::with_tone);
+    /// let mut stdout = StandardStream::stdout(ColorChoice::Auto);
+    /// renderer.write_ansi(&mut stdout, "你好，世界！\n").unwrap();
+    /// # }
+    /// ```
+    #[cfg(feature = "termcolor")]
+    pub fn write_ansi<W: WriteColor>(&self, writer: &mut W, input: &str) -> io::Result<()> {
+        let mut run: Vec<Pinyin> = vec![];
+        for (ch, pinyin) in input.chars().zip(input.to_pinyin()) {
+            match pinyin {
+                Some(pinyin) => {
+                    writer.set_color(&self.color_spec(pinyin))?;
+                    write!(writer, "{ch}")?;
+                    writer.reset()?;
+                    run.push(pinyin);
+                }
+                None => {
+                    self.write_run(writer, &mut run)?;
+                    write!(writer, "{ch}")?;
+                }
+            }
+        }
+        self.write_run(writer, &mut run)
+    }
+
+    #[cfg(feature = "termcolor")]
+    fn write_run<W: WriteColor>(&self, writer: &mut W, run: &mut Vec<Pinyin>) -> io::Result<()> {
+        if run.is_empty() {
+            return Ok(());
+        }
+        write!(writer, "(")?;
+        for (i, pinyin) in run.drain(..).enumerate() {
+            if i > 0 {
+                write!(writer, " ")?;
+            }
+            writer.set_color(&self.color_spec(pinyin))?;
+            write!(writer, "{}", (self.style)(pinyin))?;
+            writer.reset()?;
+        }
+        write!(writer, ")")
+    }
+
+    #[cfg(feature = "termcolor")]
+    fn color_spec(&self, pinyin: Pinyin) -> ColorSpec {
+        let (r, g, b) = self.palette.color(pinyin.tone());
+        let mut spec = ColorSpec::new();
+        spec.set_fg(Some(Color::Rgb(r, g, b)));
+        spec
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with_tone")]
+mod tests {
+    use crate::{Pinyin, ToneColorRenderer, TonePalette};
+
+    #[test]
+    fn html() {
+        let renderer = ToneColorRenderer::new(Pinyin::with_tone);
+        assert_eq!(
+            renderer.render_html("妈<b>"),
+            r#"<span style="color: #e30000"><ruby>妈<rt>mā</rt></ruby></span>&lt;b&gt;"#
+        );
+        assert_eq!(renderer.render_html(""), "");
+    }
+
+    #[test]
+    fn palette() {
+        assert_eq!(TonePalette::default(), TonePalette::PLECO);
+        assert_eq!(TonePalette::MDBG.color(1), (0xff, 0x00, 0x00));
+        assert_eq!(TonePalette::PLECO.color(4), (0x89, 0x00, 0xbf));
+    }
+
+    #[test]
+    #[cfg(feature = "termcolor")]
+    fn ansi() {
+        use termcolor::{Ansi, NoColor};
+
+        let renderer = ToneColorRenderer::new(Pinyin::with_tone);
+        let mut writer = NoColor::new(vec![]);
+        renderer
+            .write_ansi(&mut writer, "你好，Rust 世界！")
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "你好(nǐ hǎo)，Rust 世界(shì jiè)！"
+        );
+
+        let mut writer = Ansi::new(vec![]);
+        renderer.write_ansi(&mut writer, "妈").unwrap();
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "\x1b[0m\x1b[38;2;227;0;0m妈\x1b[0m(\x1b[0m\x1b[38;2;227;0;0mmā\x1b[0m)"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use crate::data::{PINYIN_DATA, TONE_SANDHI_TABLE};
+use crate::{Pinyin, Segment};
+
+/// 数字用字，在它们前后的 一 读本调，如 十一、一九九八
+const NUMERALS: &[&str] = &[
+    "零", "〇", "一", "二", "两", "三", "四", "五", "六", "七", "八", "九", "十",
+];
+
+/// 在它们前面的 一 读本调，如 一月、一号
+const ORDINAL_SUFFIXES: &[&str] = &["月", "日", "号"];
+
+/// 按规则改变 一、不 的声调
+///
+/// 一 在第四声前读 yí，在第一、二、三声前读 yì，单用、在词语末尾或者表示序数时读本调 yī；
+/// 不 在第四声前读 bú。
+pub(crate) fn apply_tone_sandhi(segments: &mut [Segment<'_>]) {
+    for i in 0..segments.len() {
+        let is_yi = match segments[i].text {
+            "一" => true,
+            "不" => false,
+            _ => continue,
+        };
+        let next_tone = match segments.get(i + 1).and_then(|next| next.pinyin) {
+            Some(next) => next.tone(),
+            None => continue,
+        };
+        let idx = if is_yi {
+            if is_ordinal_yi(segments, i) {
+                continue;
+            }
+            match next_tone {
+                4 => 0,
+                1..=3 => 1,
+                _ => continue,
+            }
+        } else if next_tone == 4 {
+            2
+        } else {
+            continue;
+        };
+        let idx = usize::from(TONE_SANDHI_TABLE[idx]);
+        segments[i].pinyin = Some(Pinyin(&PINYIN_DATA[idx]));
+    }
+}
+
+/// 判断位于 `i` 处的 一 是否为数字或序数，如 第一、十一、一月、零点一
+fn is_ordinal_yi(segments: &[Segment<'_>], i: usize) -> bool {
+    let text = |idx: Option<usize>| idx.and_then(|idx| segments.get(idx)).map(|s| s.text);
+    let prev = text(i.checked_sub(1));
+    let next = text(Some(i + 1));
+    match prev {
+        Some("第") => return true,
+        Some(prev) if NUMERALS.contains(&prev) => return true,
+        Some("点") if text(i.checked_sub(2)).is_some_and(|text| NUMERALS.contains(&text)) => {
+            return true
+        }
+        _ => {}
+    }
+    next.is_some_and(|next| NUMERALS.contains(&next) || ORDINAL_SUFFIXES.contains(&next))
+}
+
+#[cfg(test)]
+#[cfg(feature = "with_tone")]
+mod tests {
+    use crate::{Converter, Pinyin};
+
+    fn convert(input: &str) -> Vec<&'static str> {
+        Converter::new()
+            .tone_sandhi(true)
+            .convert(input)
+            .into_iter()
+            .filter_map(|segment| segment.pinyin.map(Pinyin::with_tone))
+            .collect()
+    }
+
+    #[test]
+    fn yi() {
+        assert_eq!(convert("一个"), vec!["yí", "gè"]);
+        assert_eq!(convert("一天"), vec!["yì", "tiān"]);
+        assert_eq!(convert("一年"), vec!["yì", "nián"]);
+        assert_eq!(convert("一百"), vec!["yì", "bǎi"]);
+        assert_eq!(convert("一"), vec!["yī"]);
+        assert_eq!(convert("统一"), vec!["tǒng", "yī"]);
+    }
+
+    #[test]
+    fn ordinal_yi() {
+        assert_eq!(convert("第一天"), vec!["dì", "yī", "tiān"]);
+        assert_eq!(convert("十一"), vec!["shí", "yī"]);
+        assert_eq!(convert("一月"), vec!["yī", "yuè"]);
+        assert_eq!(convert("二零一四"), vec!["èr", "líng", "yī", "sì"]);
+        assert_eq!(convert("三点一四"), vec!["sān", "diǎn", "yī", "sì"]);
+    }
+
+    #[test]
+    fn bu() {
+        assert_eq!(convert("不是"), vec!["bú", "shì"]);
+        assert_eq!(convert("不好"), vec!["bù", "hǎo"]);
+        assert_eq!(convert("不"), vec!["bù"]);
+    }
+}
@@ -36,6 +36,146 @@ fn pinyin_first_letter() -> io::Result<()> {
     run_test_cases("first_letter", Pinyin::first_letter)
 }
 
+#[test]
+#[cfg(feature = "yale")]
+fn pinyin_yale() -> io::Result<()> {
+    run_test_cases("yale", Pinyin::yale)
+}
+
+#[test]
+#[cfg(feature = "yale")]
+fn pinyin_yale_num() -> io::Result<()> {
+    run_test_cases("yale_num", Pinyin::yale_num)
+}
+
+#[test]
+#[cfg(feature = "mps2")]
+fn pinyin_mps2() -> io::Result<()> {
+    run_test_cases("mps2", Pinyin::mps2)
+}
+
+#[test]
+#[cfg(feature = "mps2")]
+fn pinyin_mps2_plain() -> io::Result<()> {
+    run_test_cases("mps2_plain", Pinyin::mps2_plain)
+}
+
+#[test]
+#[cfg(feature = "tongyong")]
+fn pinyin_tongyong() -> io::Result<()> {
+    run_test_cases("tongyong", Pinyin::tongyong)
+}
+
+#[test]
+#[cfg(feature = "tongyong")]
+fn pinyin_tongyong_plain() -> io::Result<()> {
+    run_test_cases("tongyong_plain", Pinyin::tongyong_plain)
+}
+
+#[test]
+#[cfg(feature = "cyrillic")]
+fn pinyin_cyrillic() -> io::Result<()> {
+    run_test_cases("cyrillic", Pinyin::cyrillic)
+}
+
+#[test]
+#[cfg(feature = "ipa")]
+fn pinyin_ipa() -> io::Result<()> {
+    run_test_cases("ipa", Pinyin::ipa)
+}
+
+#[test]
+#[cfg(feature = "ipa")]
+fn pinyin_ipa_num() -> io::Result<()> {
+    run_test_cases("ipa_num", Pinyin::ipa_num)
+}
+
+#[test]
+#[cfg(feature = "gwoyeu_romatzyh")]
+fn pinyin_gwoyeu_romatzyh() -> io::Result<()> {
+    run_test_cases("gwoyeu_romatzyh", Pinyin::gwoyeu_romatzyh)
+}
+
+#[test]
+#[cfg(feature = "hangul")]
+fn pinyin_hangul() -> io::Result<()> {
+    run_test_cases("hangul", Pinyin::hangul)
+}
+
+#[test]
+#[cfg(feature = "katakana")]
+fn pinyin_katakana() -> io::Result<()> {
+    run_test_cases("katakana", Pinyin::katakana)
+}
+
+/// 按韩国外来语标记法的汉语拼音与韩文对照表检查韩文转写
+#[test]
+#[cfg(feature = "hangul")]
+fn hangul_chart() {
+    check_chart(
+        Pinyin::hangul,
+        &[
+            // 单韵母和复韵母
+            ("八波饿爱北包欧", "바 보 어 아이 베이 바오 어우"),
+            ("安本帮等东二", "안 번 방 덩 둥 얼"),
+            // 齐齿呼，ㅈ、ㅉ、ㅊ 后的 ㅑ、ㅖ、ㅛ、ㅠ 写作 ㅏ、ㅔ、ㅗ、ㅜ
+            ("一家下别写叶小要", "이 자 샤 볘 셰 예 샤오 야오"),
+            (
+                "六九有天见眼心良江明兄用",
+                "류 주 유 톈 젠 옌 신 량 장 밍 슝 융",
+            ),
+            // 合口呼
+            ("五花我国外快为贵", "우 화 워 궈 와이 콰이 웨이 구이"),
+            ("万关文春王光翁", "완 관 원 춘 왕 광 웡"),
+            // 撮口呼
+            ("女去鱼学月元全云军", "뉘 취 위 쉐 웨 위안 취안 윈 쥔"),
+            // 舌尖元音
+            ("知吃是日子次四", "즈 츠 스 르 쯔 츠 쓰"),
+            ("中热北京上海", "중 러 베이 징 상 하이"),
+        ],
+    );
+}
+
+/// 按常用的片假名写法检查片假名转写
+#[test]
+#[cfg(feature = "katakana")]
+fn katakana_chart() {
+    check_chart(
+        Pinyin::katakana,
+        &[
+            ("北京上海", "ベイ ジン シャン ハイ"),
+            ("习近平", "シー ジン ピン"),
+            ("胡锦涛", "フー ジン タオ"),
+            ("温家宝", "ウェン ジア バオ"),
+            ("邓小平", "ドン シャオ ピン"),
+            ("毛周王刘", "マオ ジョウ ワン リウ"),
+            (
+                "条佛风曹宋孙左",
+                "ティアオ フォー フォン ツァオ ソン スン ズオ",
+            ),
+            (
+                "人日四如若瑞水对",
+                "レン リー スー ルー ルオ ルイ シュイ ドゥイ",
+            ),
+            (
+                "一于吕学元云亚有",
+                "イー ユー リュー シュエ ユエン ユン ヤー ヨウ",
+            ),
+        ],
+    );
+}
+
+#[cfg(any(feature = "hangul", feature = "katakana"))]
+fn check_chart(converter: fn(Pinyin) -> &'static str, chart: &[(&str, &str)]) {
+    for (input, expected) in chart {
+        let result = input
+            .to_pinyin()
+            .map(|pinyin| converter(pinyin.unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(result.join(" "), *expected, "unmatched result for {input}");
+    }
+}
+
 fn run_test_cases(suffix: &str, converter: fn(Pinyin) -> &'static str) -> io::Result<()> {
     let test_cases = list_test_cases()?;
     for input_path in test_cases.iter() {